@@ -0,0 +1,504 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use adventofcode25::parse::records;
+use adventofcode25::{AocError, InputSource, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 1;
+const DIAL_SIZE: u64 = 100;
+const DIAL_START: u64 = 50;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Rotation>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "trace",
+            usage: "[--csv] [--size <n>] [--start <n>] [--alphabet <lr>]: the dial around every rotation, checked click by click",
+            run: trace,
+        },
+        Tool {
+            name: "dials",
+            usage: "[--dial <size>[@<start>]]... [--alphabet <lr>]: the zero counts of several dials turned by the same rotations",
+            run: dials,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_rotations(input, Alphabet::LR)
+    }
+
+    fn part1(rotations: &Self::Input) -> u64 {
+        Dial::new(DIAL_SIZE, DIAL_START)
+            .count_zeros(rotations)
+            .landed
+    }
+
+    fn part2(rotations: &Self::Input) -> u128 {
+        Dial::new(DIAL_SIZE, DIAL_START)
+            .count_zeros(rotations)
+            .clicked
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// The letters that spell the two directions in an instruction list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    pub left: char,
+    pub right: char,
+}
+
+impl Alphabet {
+    /// `L68`, `R14`, ... as used by the puzzle.
+    pub const LR: Alphabet = Alphabet {
+        left: 'L',
+        right: 'R',
+    };
+
+    fn letter(&self, direction: Direction) -> char {
+        match direction {
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+        }
+    }
+
+    fn direction(&self, c: char) -> Option<Direction> {
+        if c == self.left {
+            Some(Direction::Left)
+        } else if c == self.right {
+            Some(Direction::Right)
+        } else {
+            None
+        }
+    }
+}
+
+/// Two different letters, left then right, such as `LR` or `<>`.
+impl FromStr for Alphabet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match *s.chars().collect::<Vec<_>>() {
+            [left, right] if left != right => Ok(Alphabet { left, right }),
+            _ => Err(format!(
+                "expected two different letters for left and right, found {s:?}"
+            )),
+        }
+    }
+}
+
+/// One instruction, which is displayed in the alphabet it was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: u64,
+    pub alphabet: Alphabet,
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.alphabet.letter(self.direction),
+            self.distance
+        )
+    }
+}
+
+/// Parses one rotation per line, a direction letter of `alphabet` followed by a distance.
+pub fn parse_rotations(input: &str, alphabet: Alphabet) -> Result<Vec<Rotation>> {
+    records(input, |line| {
+        let rotation = line.text.trim();
+        let mut chars = rotation.chars();
+        let Some(direction) = chars.next().and_then(|c| alphabet.direction(c)) else {
+            return Err(line.error(
+                rotation,
+                format!(
+                    "expected a rotation like {}68 or {}14, found {rotation:?}",
+                    alphabet.left, alphabet.right
+                ),
+            ));
+        };
+        let distance = line.parse(chars.as_str())?;
+        Ok(Rotation {
+            direction,
+            distance,
+            alphabet,
+        })
+    })
+}
+
+/// How often a dial pointed at zero: after a rotation, or at any click along the way.
+///
+/// A single rotation passes zero at most `u64::MAX` times, so many of them can exceed a `u64`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroCount {
+    pub landed: u64,
+    pub clicked: u128,
+}
+
+/// A circular dial numbered `0..size`, pointing at `position`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+}
+
+impl Dial {
+    pub fn new(size: u64, start: u64) -> Self {
+        assert!(
+            start < size,
+            "start {start} is not on a dial of size {size}"
+        );
+        Dial {
+            size,
+            position: start,
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Turns the dial by `distance` clicks, returning how often it pointed at zero on the way
+    /// (including where it stops) and whether it stopped there.
+    ///
+    /// The arithmetic is done in `u128`, so any size and distance fit.
+    pub fn rotate(&mut self, direction: Direction, distance: u64) -> (u64, bool) {
+        let (size, position, distance) = (
+            u128::from(self.size),
+            u128::from(self.position),
+            u128::from(distance),
+        );
+        // Turning left from `p` passes zero as often as turning right from `size - p`.
+        let from = match direction {
+            Direction::Right => position,
+            Direction::Left => (size - position) % size,
+        };
+        // `from < size`, so this is at most `distance` and fits a `u64` again.
+        let clicks = ((from + distance) / size) as u64;
+        let step = distance % size;
+        self.position = match direction {
+            Direction::Right => (position + step) % size,
+            Direction::Left => (position + size - step) % size,
+        } as u64;
+        (clicks, self.position == 0)
+    }
+
+    /// The same as [`Dial::rotate`], but moving one click at a time: slow, and obviously right.
+    pub fn rotate_click_by_click(&mut self, direction: Direction, distance: u64) -> (u64, bool) {
+        let mut clicks = 0;
+        for _ in 0..distance {
+            self.position = match direction {
+                Direction::Right if self.position == self.size - 1 => 0,
+                Direction::Right => self.position + 1,
+                Direction::Left if self.position == 0 => self.size - 1,
+                Direction::Left => self.position - 1,
+            };
+            clicks += u64::from(self.position == 0);
+        }
+        (clicks, self.position == 0)
+    }
+
+    /// Applies all `rotations` in turn and totals the zero counts.
+    pub fn count_zeros(&mut self, rotations: &[Rotation]) -> ZeroCount {
+        let mut count = ZeroCount::default();
+        for rotation in rotations {
+            let (clicks, landed) = self.rotate(rotation.direction, rotation.distance);
+            count.clicked += u128::from(clicks);
+            count.landed += u64::from(landed);
+        }
+        count
+    }
+}
+
+/// Runs the same `rotations` on each of `dials`, returning the zero counts per dial.
+pub fn count_zeros_on_dials(dials: &mut [Dial], rotations: &[Rotation]) -> Vec<ZeroCount> {
+    dials
+        .iter_mut()
+        .map(|dial| dial.count_zeros(rotations))
+        .collect()
+}
+
+/// `<size>` or `<size>@<start>`, starting at 0 by default.
+impl FromStr for Dial {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let (size, start) = s.split_once('@').unwrap_or((s, "0"));
+        let number = |text: &str| {
+            text.parse::<u64>()
+                .map_err(|e| format!("{text:?} in dial {s:?}: {e}"))
+        };
+        let (size, start) = (number(size)?, number(start)?);
+        if start >= size {
+            return Err(format!("start {start} is not on a dial of size {size}"));
+        }
+        Ok(Dial::new(size, start))
+    }
+}
+
+/// One rotation as seen by the trace, with the zero count of the click-by-click reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep {
+    pub rotation: Rotation,
+    pub from: u64,
+    pub to: u64,
+    pub clicks: u64,
+    pub landed: bool,
+    pub reference_clicks: u64,
+    pub reference_to: u64,
+}
+
+impl TraceStep {
+    pub fn matches_reference(&self) -> bool {
+        self.clicks == self.reference_clicks && self.to == self.reference_to
+    }
+}
+
+/// Applies `rotations` to `dial` and to a copy turned click by click, recording every step.
+pub fn trace_rotations(dial: Dial, rotations: &[Rotation]) -> Vec<TraceStep> {
+    let mut dial = dial;
+    let mut reference = dial;
+    rotations
+        .iter()
+        .map(|&rotation| {
+            let from = dial.position();
+            let (clicks, landed) = dial.rotate(rotation.direction, rotation.distance);
+            let (reference_clicks, _) =
+                reference.rotate_click_by_click(rotation.direction, rotation.distance);
+            TraceStep {
+                rotation,
+                from,
+                to: dial.position(),
+                clicks,
+                landed,
+                reference_clicks,
+                reference_to: reference.position(),
+            }
+        })
+        .collect()
+}
+
+pub fn trace_table(steps: &[TraceStep]) -> String {
+    let mut table = format!(
+        "{:>5}  {:<8}  {:>5}  {:>5}  {:>6}  {:>6}  {:>6}\n",
+        "step", "rotation", "from", "to", "zeros", "landed", "total"
+    );
+    let mut total = ZeroCount::default();
+    let mut mismatches = 0;
+    for (index, step) in steps.iter().enumerate() {
+        total.clicked += u128::from(step.clicks);
+        total.landed += u64::from(step.landed);
+        table.push_str(&format!(
+            "{:>5}  {:<8}  {:>5}  {:>5}  {:>6}  {:>6}  {:>6}",
+            index + 1,
+            step.rotation.to_string(),
+            step.from,
+            step.to,
+            step.clicks,
+            if step.landed { "yes" } else { "" },
+            total.clicked
+        ));
+        if !step.matches_reference() {
+            mismatches += 1;
+            table.push_str(&format!(
+                "  MISMATCH: click by click gives {} zeros, ending at {}",
+                step.reference_clicks, step.reference_to
+            ));
+        }
+        table.push('\n');
+    }
+    table.push_str(&format!(
+        "landed on zero {} times, pointed at zero {} times, {} mismatches with the reference\n",
+        total.landed, total.clicked, mismatches
+    ));
+    table
+}
+
+pub fn trace_csv(steps: &[TraceStep]) -> String {
+    let mut csv = String::from("step,rotation,from,to,zeros,landed,reference_zeros,reference_to\n");
+    for (index, step) in steps.iter().enumerate() {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            index + 1,
+            step.rotation,
+            step.from,
+            step.to,
+            step.clicks,
+            step.landed,
+            step.reference_clicks,
+            step.reference_to
+        ));
+    }
+    csv
+}
+
+fn trace(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--csv"], &["--size", "--start", "--alphabet"])?;
+    let size = args.value("--size")?.unwrap_or(DIAL_SIZE);
+    let start = args.value("--start")?.unwrap_or(DIAL_START);
+    if start >= size {
+        return Err(AocError::InvalidArgument(format!(
+            "start {start} is not on a dial of size {size}"
+        )));
+    }
+
+    let alphabet = args.value("--alphabet")?.unwrap_or(Alphabet::LR);
+    let rotations = parse_rotations(&source.read(DAY)?, alphabet)?;
+    let steps = trace_rotations(Dial::new(size, start), &rotations);
+    if args.flag("--csv") {
+        print!("{}", trace_csv(&steps));
+    } else {
+        print!("{}", trace_table(&steps));
+    }
+    Ok(())
+}
+
+/// Prints, for each dial, how often it landed on and passed zero.
+fn dials(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &[], &["--dial", "--alphabet"])?;
+    let alphabet = args.value("--alphabet")?.unwrap_or(Alphabet::LR);
+    let mut dials = args
+        .values("--dial")
+        .into_iter()
+        .collect::<Result<Vec<Dial>>>()?;
+    if dials.is_empty() {
+        dials.push(Dial::new(DIAL_SIZE, DIAL_START));
+    }
+
+    let rotations = parse_rotations(&source.read(DAY)?, alphabet)?;
+    let descriptions: Vec<String> = dials
+        .iter()
+        .map(|dial| format!("{}@{}", dial.size, dial.position))
+        .collect();
+    let counts = count_zeros_on_dials(&mut dials, &rotations);
+    println!("{:>10}  {:>6}  {:>7}", "dial", "landed", "clicked");
+    for (description, count) in descriptions.iter().zip(counts) {
+        println!(
+            "{description:>10}  {:>6}  {:>7}",
+            count.landed, count.clicked
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day01>(example_path(DAY))?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day01>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day01>(example_path(DAY))?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day01>()
+    }
+
+    #[test]
+    fn rotate_matches_click_by_click_reference() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for size in [1, 2, 7, 100] {
+            let rotations: Vec<Rotation> = (0..500)
+                .map(|_| Rotation {
+                    direction: if rng.below(2) == 0 {
+                        Direction::Left
+                    } else {
+                        Direction::Right
+                    },
+                    distance: rng.below(4 * size + 2),
+                    alphabet: Alphabet::LR,
+                })
+                .collect();
+            let steps = trace_rotations(Dial::new(size, rng.below(size)), &rotations);
+            let mismatch = steps.iter().position(|step| !step.matches_reference());
+            assert_eq!(
+                mismatch,
+                None,
+                "size {size}: {:?}",
+                mismatch.map(|i| steps[i])
+            );
+        }
+    }
+
+    #[test]
+    fn dials_of_other_sizes_and_alphabets() -> Result<()> {
+        let alphabet: Alphabet = "<>".parse().unwrap();
+        let rotations = parse_rotations("<3\n>10\n<7\n", alphabet)?;
+        assert_eq!(rotations[1].to_string(), ">10");
+        let mut dials = ["10@3", "5"].map(|dial| dial.parse::<Dial>().unwrap());
+        assert_eq!(dials[1], Dial::new(5, 0));
+        assert!("10@10".parse::<Dial>().is_err() && "<".parse::<Alphabet>().is_err());
+
+        // Distances and sizes up to u64::MAX don't overflow.
+        let max = u64::MAX;
+        let (right, left) = (Direction::Right, Direction::Left);
+        assert_eq!(
+            Dial::new(100, 50).rotate(right, max),
+            (184467440737095516, false)
+        );
+        assert_eq!(
+            Dial::new(100, 50).rotate(left, max),
+            (184467440737095516, false)
+        );
+        assert_eq!(Dial::new(max, max - 1).rotate(right, max), (1, false));
+        assert_eq!(Dial::new(max, 0).rotate(left, max), (1, true));
+        let steps = trace_rotations(
+            Dial::new(max, max - 1),
+            &parse_rotations("R3\nL5\n", Alphabet::LR)?,
+        );
+        assert!(steps.iter().all(TraceStep::matches_reference), "{steps:?}");
+        let huge = parse_rotations(&format!("R{max}\nL{max}\nR{max}\n"), Alphabet::LR)?;
+        assert_eq!(
+            Dial::new(1, 0).count_zeros(&huge).clicked,
+            3 * u128::from(max)
+        );
+        let counts = count_zeros_on_dials(&mut dials, &rotations);
+        // 3 -> 0 (lands), -> 0 (one full turn, lands), -> 3.
+        assert_eq!(
+            counts[0],
+            ZeroCount {
+                landed: 2,
+                clicked: 2
+            }
+        );
+        // 0 -> 2, -> 2 (two full turns), -> 0 (passes zero once on the way, then lands).
+        assert_eq!(
+            counts[1],
+            ZeroCount {
+                landed: 1,
+                clicked: 4
+            }
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+use std::str::FromStr;
+
+use adventofcode25::parse::lines;
+use adventofcode25::{AocError, InputSource, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const TOOLS: &'static [Tool] = &[Tool {
+        name: "ids",
+        usage: "[--radix <2-36>] [--repeats <k|k+|any>] [--list]: the sum of invalid IDs under other rules",
+        run: variant,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut ranges = Vec::new();
+        for line in lines(input) {
+            for range in line.text.split(',').map(str::trim) {
+                if range.is_empty() {
+                    continue;
+                }
+                let (start, end) = line.split_token(range, "-")?;
+                ranges.push((line.parse(start)?, line.parse(end)?));
+            }
+        }
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Self::Input) -> u64 {
+        sum_invalid_ids(ranges, 10, Repetition::Exactly(2))
+    }
+
+    fn part2(ranges: &Self::Input) -> u64 {
+        sum_invalid_ids(ranges, 10, Repetition::Any)
+    }
+}
+
+/// How many times the digit pattern of an invalid ID has to be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repetition {
+    Exactly(u32),
+    AtLeast(u32),
+    /// Any number of times, as long as it repeats: the same as `AtLeast(2)`.
+    Any,
+}
+
+impl Repetition {
+    fn accepts(self, count: u32) -> bool {
+        match self {
+            Repetition::Exactly(k) => count == k,
+            Repetition::AtLeast(k) => count >= k,
+            Repetition::Any => count >= 2,
+        }
+    }
+}
+
+impl FromStr for Repetition {
+    type Err = String;
+
+    /// `3` for exactly three times, `3+` for at least three times, or `any`. A pattern is
+    /// always repeated at least twice, so smaller counts are rejected.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let count = |k: &str| {
+            k.parse()
+                .ok()
+                .filter(|&count: &u32| count >= 2)
+                .ok_or_else(|| format!("expected a count like 2, 3+ or any, found {s:?}"))
+        };
+        match s {
+            "any" => Ok(Repetition::Any),
+            _ => match s.strip_suffix('+') {
+                Some(k) => count(k).map(Repetition::AtLeast),
+                None => count(s).map(Repetition::Exactly),
+            },
+        }
+    }
+}
+
+pub fn sum_invalid_ids(ranges: &[(u64, u64)], radix: u32, repetition: Repetition) -> u64 {
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            invalid_ids(start, end, radix, repetition)
+                .iter()
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Every number in `start..=end` whose digits in base `radix` are a single pattern written a
+/// number of times accepted by `repetition`, in increasing order.
+///
+/// Such a number of `length` digits is `pattern * multiplier`, where the multiplier is the
+/// repunit `1 0..0 1 0..0 1` with one `1` per repetition. Only patterns whose product lies in
+/// the range are generated. A number can have several periods (`111111` is `1`, `11` and `111`
+/// repeated), so the candidates are deduplicated.
+pub fn invalid_ids(start: u64, end: u64, radix: u32, repetition: Repetition) -> Vec<u64> {
+    assert!(radix >= 2, "radix {radix} has no digits to repeat");
+    let digits = |n: u64| n.checked_ilog(u64::from(radix)).unwrap_or(0) + 1;
+    let radix = u128::from(radix);
+    let mut ids = Vec::new();
+    for length in digits(start)..=digits(end) {
+        for period in 1..=length / 2 {
+            if !length.is_multiple_of(period) || !repetition.accepts(length / period) {
+                continue;
+            }
+            // u128, since the multiplier of a 20-digit number does not fit a u64.
+            let multiplier: u128 = (0..length / period).map(|i| radix.pow(i * period)).sum();
+            let first = radix
+                .pow(period - 1)
+                .max(u128::from(start).div_ceil(multiplier));
+            let last = (radix.pow(period) - 1).min(u128::from(end) / multiplier);
+            ids.extend((first..=last).map(|pattern| (pattern * multiplier) as u64));
+        }
+    }
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+fn variant(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--list"], &["--radix", "--repeats"])?;
+    let radix = args.value("--radix")?.unwrap_or(10);
+    if !(2..=36).contains(&radix) {
+        return Err(AocError::InvalidArgument(format!(
+            "radix {radix} is not between 2 and 36"
+        )));
+    }
+    let repetition = args.value("--repeats")?.unwrap_or(Repetition::Any);
+
+    let ranges = Day02::parse(&source.read(DAY)?)?;
+    if args.flag("--list") {
+        for &(start, end) in &ranges {
+            for id in invalid_ids(start, end, radix, repetition) {
+                println!("{id} ({})", to_radix(id, radix));
+            }
+        }
+    }
+    println!("{}", sum_invalid_ids(&ranges, radix, repetition));
+    Ok(())
+}
+
+fn to_radix(mut number: u64, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((number % u64::from(radix)) as u32, radix).unwrap());
+        number /= u64::from(radix);
+        if number == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day02>(example_path(DAY))?, 1227775554);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day02>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day02>(example_path(DAY))?, 4174379265);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day02>()
+    }
+
+    /// The original scan, generalised: write out every number and compare its chunks.
+    fn brute_force_ids(start: u64, end: u64, radix: u32, repetition: Repetition) -> Vec<u64> {
+        (start..=end)
+            .filter(|&number| {
+                let digits = to_radix(number, radix).into_bytes();
+                let length = digits.len();
+                (1..=length / 2).any(|period| {
+                    length.is_multiple_of(period)
+                        && repetition.accepts((length / period) as u32)
+                        && digits
+                            .chunks(period)
+                            .all(|chunk| chunk == &digits[..period])
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn enumeration_matches_brute_force() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let policies = [
+            Repetition::Exactly(2),
+            Repetition::Exactly(3),
+            Repetition::AtLeast(3),
+            Repetition::Any,
+        ];
+        for radix in [2, 3, 10, 16] {
+            for _ in 0..100 {
+                let magnitude = u64::from(radix).pow(2 + rng.below(5) as u32 * 2);
+                let start = rng.below(magnitude);
+                let end = start + rng.below(3_000);
+                for repetition in policies {
+                    assert_eq!(
+                        invalid_ids(start, end, radix, repetition),
+                        brute_force_ids(start, end, radix, repetition),
+                        "{start}-{end} in base {radix}, {repetition:?}"
+                    );
+                }
+            }
+        }
+        // Wide ranges stay cheap: only the matching numbers are generated.
+        let twice = Repetition::Exactly(2);
+        assert_eq!(invalid_ids(1, 10u64.pow(12), 10, twice).len(), 999_999);
+        assert_eq!(
+            invalid_ids(u64::MAX - 10u64.pow(10), u64::MAX, 10, twice),
+            [18_446_744_071_844_674_407]
+        );
+        assert_eq!("3+".parse(), Ok(Repetition::AtLeast(3)));
+        for bad in ["x", "0", "1", "0+", "1+"] {
+            assert!(bad.parse::<Repetition>().is_err(), "{bad}");
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+
+use adventofcode25::parse::records;
+use adventofcode25::{AocError, InputSource, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Bank>;
+    type Answer1 = Result<u64>;
+    type Answer2 = Result<u64>;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "joltage",
+            usage: "[--strategy window|stack] [--count <k>]: the total joltage picking k batteries per bank",
+            run: joltage,
+        },
+        Tool {
+            name: "explain",
+            usage: "[--strategy window|stack] [--count <k>] [--color|--no-color]: every bank with the chosen batteries highlighted",
+            run: explain,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        records(input, |line| {
+            let digits = line
+                .text
+                .char_indices()
+                .map(|(column, c)| {
+                    c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                        let message = format!("expected a digit, found {c:?}");
+                        AocError::parse(line.number, column + 1, message)
+                    })
+                })
+                .collect::<Result<Vec<u8>>>()?;
+            Ok(Bank {
+                line: line.number,
+                digits,
+            })
+        })
+    }
+
+    fn part1(banks: &Self::Input) -> Result<u64> {
+        run(banks, 2, Strategy::default())
+    }
+
+    fn part2(banks: &Self::Input) -> Result<u64> {
+        run(banks, 12, Strategy::default())
+    }
+}
+
+/// How the highest `count` digits of a bank are picked. Both give the same answer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Picks each digit as the highest in the window that still leaves room for the rest: O(n·k).
+    Window,
+    /// Drops every digit followed by a higher one while digits may still be dropped: O(n).
+    #[default]
+    Stack,
+}
+
+impl Strategy {
+    /// The positions of the batteries to turn on in `digits`, in increasing order, and the
+    /// joltage they produce. `count` may be at most 19, the digits of a `u64`.
+    pub fn select(self, digits: &[u8], count: usize) -> Selection {
+        let indices = match self {
+            Strategy::Window => find_highest_digits(digits, count),
+            Strategy::Stack => find_highest_digits_stack(digits, count),
+        };
+        let joltage = indices
+            .iter()
+            .fold(0u64, |acc, &i| acc * 10 + digits[i] as u64);
+        Selection { indices, joltage }
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "window" => Ok(Strategy::Window),
+            "stack" => Ok(Strategy::Stack),
+            _ => Err(format!("expected window or stack, found {s:?}")),
+        }
+    }
+}
+
+/// One bank of batteries and the input line it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bank {
+    pub line: usize,
+    pub digits: Vec<u8>,
+}
+
+/// The batteries picked from one bank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub indices: Vec<usize>,
+    pub joltage: u64,
+}
+
+/// The total joltage picking `digit_count` batteries per bank, or the first bank too short for it.
+fn run(banks: &[Bank], digit_count: usize, strategy: Strategy) -> Result<u64> {
+    let mut total = 0;
+    for bank in banks {
+        if bank.digits.len() < digit_count {
+            return Err(AocError::shape(format!(
+                "bank on line {} has {} batteries, at least {digit_count} are needed",
+                bank.line,
+                bank.digits.len()
+            )));
+        }
+        total += strategy.select(&bank.digits, digit_count).joltage;
+    }
+    Ok(total)
+}
+
+/// The tool options shared by `joltage` and `explain`.
+fn tool_setup(source: &InputSource, args: &ToolArgs) -> Result<(Vec<Bank>, usize, Strategy)> {
+    let strategy = args.value("--strategy")?.unwrap_or_default();
+    let count = args.value("--count")?.unwrap_or(12);
+    let banks = Day03::parse(&source.read(DAY)?)?;
+    if count > 19 || banks.iter().any(|bank| bank.digits.len() < count) {
+        return Err(AocError::InvalidArgument(format!(
+            "--count {count} needs banks of at least {count} batteries and at most 19 digits"
+        )));
+    }
+    Ok((banks, count, strategy))
+}
+
+fn joltage(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &[], &["--strategy", "--count"])?;
+    let (banks, count, strategy) = tool_setup(source, &args)?;
+    println!("{}", run(&banks, count, strategy)?);
+    Ok(())
+}
+
+/// Prints every bank with the chosen batteries highlighted and the joltage they give.
+fn explain(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--color", "--no-color"], &["--strategy", "--count"])?;
+    let (banks, count, strategy) = tool_setup(source, &args)?;
+    let color = args.flag("--color") || (!args.flag("--no-color") && io::stdout().is_terminal());
+    let mut total = 0;
+    for bank in &banks {
+        let selection = strategy.select(&bank.digits, count);
+        print!("{}", highlight(&bank.digits, &selection, color));
+        total += selection.joltage;
+    }
+    println!("total {total}");
+    Ok(())
+}
+
+/// One bank followed by its joltage. Chosen batteries are shown in bold green when `color`
+/// is set, and marked with `^` on a second line otherwise.
+fn highlight(digits: &[u8], selection: &Selection, color: bool) -> String {
+    let mut chosen = selection.indices.iter().peekable();
+    let mut bank = String::new();
+    let mut marks = String::new();
+    for (i, &digit) in digits.iter().enumerate() {
+        let picked = chosen.next_if_eq(&&i).is_some();
+        match (picked, color) {
+            (true, true) => bank.push_str(&format!("\x1b[1;32m{digit}\x1b[0m")),
+            _ => bank.push(char::from(b'0' + digit)),
+        }
+        marks.push(if picked { '^' } else { ' ' });
+    }
+    if color {
+        format!("{bank}  {}\n", selection.joltage)
+    } else {
+        format!("{bank}  {}\n{}\n", selection.joltage, marks.trim_end())
+    }
+}
+
+/// The indices of the highest `count`-digit subsequence, taking each digit as the first
+/// occurrence of the highest one in the window that still leaves room for the rest.
+fn find_highest_digits(digits: &[u8], count: usize) -> Vec<usize> {
+    let mut result = Vec::with_capacity(count);
+    let mut current_index = 0;
+
+    for i in 0..count {
+        let remaining = count - i - 1;
+        let end_index = digits.len() - remaining;
+        let window = &digits[current_index..end_index];
+
+        let idx = current_index + highest_in_slice(window).unwrap();
+        result.push(idx);
+        current_index = idx + 1;
+    }
+    result
+}
+
+/// The index of the first occurrence of the highest digit in `slice`.
+fn highest_in_slice(slice: &[u8]) -> Option<usize> {
+    let mut highest: Option<(u8, usize)> = None;
+    for (idx, &d) in slice.iter().enumerate() {
+        if highest.is_none_or(|(max_digit, _)| d > max_digit) {
+            highest = Some((d, idx));
+        }
+    }
+    highest.map(|(_, idx)| idx)
+}
+
+// --- O(n) stack-based solution ---
+
+/// O(n) solution using monotonic stack.
+/// Instead of repeatedly finding max in windows, we greedily remove
+/// (n - k) digits that would make the result smaller. The stack holds indices.
+fn find_highest_digits_stack(digits: &[u8], count: usize) -> Vec<usize> {
+    let to_remove = digits.len() - count;
+    let mut stack: Vec<usize> = Vec::with_capacity(digits.len());
+    let mut removed = 0;
+
+    for (i, &d) in digits.iter().enumerate() {
+        while removed < to_remove && stack.last().is_some_and(|&top| digits[top] < d) {
+            stack.pop();
+            removed += 1;
+        }
+        stack.push(i);
+    }
+
+    stack.truncate(count);
+    stack
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day03>(example_path(DAY))?, 357);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day03>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day03>(example_path(DAY))?, 3121910778619);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day03>()
+    }
+
+    #[test]
+    fn short_banks_only_fail_larger_counts() -> Result<()> {
+        let banks = Day03::parse("987654321111111\n\n818181\n")?;
+        assert_eq!(Day03::part1(&banks)?, 98 + 88);
+        let error = Day03::part2(&banks).unwrap_err().to_string();
+        assert!(
+            error.contains("line 3 has 6 batteries, at least 12"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn strategies_agree_on_random_banks() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..500 {
+            let len = 1 + rng.below(30) as usize;
+            // Few distinct digits give many ties, which is where the two differ most in approach.
+            let highest = 1 + rng.below(10);
+            let digits: Vec<u8> = (0..len).map(|_| rng.below(highest) as u8).collect();
+            for count in 0..=len {
+                let window = find_highest_digits(&digits, count);
+                assert_eq!(window.len(), count);
+                assert!(window.is_sorted_by(|a, b| a < b));
+                assert_eq!(
+                    find_highest_digits_stack(&digits, count),
+                    window,
+                    "{digits:?}, count {count}"
+                );
+            }
+            let all: Vec<usize> = (0..len).collect();
+            assert_eq!(find_highest_digits_stack(&digits, len), all);
+        }
+        assert_eq!(find_highest_digits(&[0, 0, 1], 3), [0, 1, 2]);
+        let selection = Strategy::Window.select(&[8, 1, 9, 2, 9], 2);
+        assert_eq!(selection.indices, [2, 4]);
+        assert_eq!(selection.joltage, 99);
+        assert_eq!(
+            highlight(&[8, 1, 9, 2, 9], &selection, false),
+            "81929  99\n  ^ ^\n"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use adventofcode25::{
+    AocError, Grid, Image, InputSource, Pos, Result, Rgb, Solution, Tool, ToolArgs, encode_gif,
+    write_output,
+};
+
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = DAY;
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "rounds",
+            usage: "the number of rolls removed in each round",
+            run: rounds,
+        },
+        Tool {
+            name: "frames",
+            usage: "[--format text|ppm|png] [--out <dir>] [--scale <px>] [--gif <file>] [--delay <1/100 s>]: the grid after each round",
+            run: frames,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_str(input, |c| match c {
+            '@' => Some(1),
+            '.' => Some(0),
+            _ => None,
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        find_positions(grid, MAX_SURROUNDING).len()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        removal_rounds(grid).iter().map(Vec::len).sum()
+    }
+}
+
+/// A roll can be taken away by a forklift when fewer than four of its neighbours are rolls.
+const MAX_SURROUNDING: u8 = 3;
+
+fn find_positions(grid: &Grid<u8>, max_surrounding: u8) -> Vec<Pos> {
+    grid.iter()
+        .filter(|&(pos, &value)| value == 1 && check_position(grid, pos, max_surrounding))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn check_position(grid: &Grid<u8>, pos: Pos, max_surrounding: u8) -> bool {
+    let mut surrounding_count = 0;
+    for neighbor in grid.neighbors8(pos) {
+        surrounding_count += grid[neighbor];
+        if surrounding_count > max_surrounding {
+            return false;
+        }
+    }
+    true
+}
+
+/// The rolls removed in each round when every accessible roll is removed at once, until
+/// none is left accessible. The rounds are in order, each sorted by position.
+///
+/// Instead of rescanning the grid every round, the number of neighbouring rolls is kept per
+/// cell. Removing a roll only lowers the counts of its neighbours, so the next round consists
+/// of exactly the neighbours whose count has just dropped to the limit.
+pub fn removal_rounds(grid: &Grid<u8>) -> Vec<Vec<Pos>> {
+    let mut present = grid.map(|&cell| cell == 1);
+    let mut counts = grid.map(|_| 0u8);
+    for (pos, _) in grid.iter().filter(|&(_, &cell)| cell == 1) {
+        counts[pos] = grid.neighbors8(pos).map(|n| grid[n]).sum();
+    }
+
+    let mut rounds = Vec::new();
+    let mut round = find_positions(grid, MAX_SURROUNDING);
+    while !round.is_empty() {
+        for &pos in &round {
+            present[pos] = false;
+        }
+        let mut next = Vec::new();
+        for &pos in &round {
+            for neighbor in grid.neighbors8(pos) {
+                if present[neighbor] {
+                    counts[neighbor] -= 1;
+                    // Counts only go down, so each roll crosses the limit at most once.
+                    if counts[neighbor] == MAX_SURROUNDING {
+                        next.push(neighbor);
+                    }
+                }
+            }
+        }
+        next.sort_unstable();
+        rounds.push(std::mem::replace(&mut round, next));
+    }
+    rounds
+}
+
+fn rounds(source: &InputSource, args: &[String]) -> Result<()> {
+    ToolArgs::parse(args, &[], &[])?;
+    let grid = Day04::parse(&source.read(DAY)?)?;
+    let rounds = removal_rounds(&grid);
+    let mut total = 0;
+    println!("round  removed  total");
+    for (index, round) in rounds.iter().enumerate() {
+        total += round.len();
+        println!("{:>5}  {:>7}  {total:>5}", index + 1, round.len());
+    }
+    Ok(())
+}
+
+/// A cell in a frame of the removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Roll,
+    /// A roll taken away in the round the frame shows.
+    Removed,
+}
+
+impl Cell {
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Empty => [24, 24, 32],
+            Cell::Roll => [220, 220, 210],
+            Cell::Removed => [230, 60, 40],
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Roll => '@',
+            Cell::Removed => 'x',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// The grid before the first round, then after each round of [`removal_rounds`] with the
+/// rolls it removed marked.
+pub fn removal_frames(grid: &Grid<u8>) -> Vec<Grid<Cell>> {
+    let mut state = grid.map(|&cell| if cell == 1 { Cell::Roll } else { Cell::Empty });
+    let mut frames = vec![state.clone()];
+    for round in removal_rounds(grid) {
+        for &pos in &round {
+            state[pos] = Cell::Removed;
+        }
+        frames.push(state.clone());
+        for &pos in &round {
+            state[pos] = Cell::Empty;
+        }
+    }
+    frames
+}
+
+fn frames(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(
+        args,
+        &[],
+        &["--format", "--out", "--scale", "--gif", "--delay"],
+    )?;
+    let format: String = args
+        .value("--format")?
+        .unwrap_or_else(|| "text".to_string());
+    let scale = args.value("--scale")?.unwrap_or(4).max(1);
+    let delay = args.value("--delay")?.unwrap_or(20);
+    let grid = Day04::parse(&source.read(DAY)?)?;
+    let frames = removal_frames(&grid);
+
+    let out: Option<PathBuf> = args.value("--out")?;
+    match (format.as_str(), &out) {
+        ("text", None) => {
+            for (round, frame) in frames.iter().enumerate() {
+                println!("round {round}\n{frame}");
+            }
+        }
+        ("text" | "ppm" | "png", Some(out)) => {
+            fs::create_dir_all(out).map_err(|source| AocError::Write {
+                path: out.clone(),
+                source,
+            })?;
+            let extension = if format == "text" { "txt" } else { &format };
+            for (round, frame) in frames.iter().enumerate() {
+                let path = out.join(format!("round_{round:03}.{extension}"));
+                let image = || Image::from_grid(frame, scale, Cell::color);
+                match format.as_str() {
+                    "ppm" => write_output(path, image().to_ppm())?,
+                    "png" => write_output(path, image().to_png()?)?,
+                    _ => write_output(path, frame.to_string())?,
+                }
+            }
+            println!("wrote {} frames to {}", frames.len(), out.display());
+        }
+        ("ppm" | "png", None) => {
+            return Err(AocError::InvalidArgument(format!(
+                "--format {format} needs --out <dir>"
+            )));
+        }
+        _ => {
+            return Err(AocError::InvalidArgument(format!(
+                "--format {format}: expected text, ppm or png"
+            )));
+        }
+    }
+
+    if let Some(gif) = args.value::<PathBuf>("--gif")? {
+        let images: Vec<Image> = frames
+            .iter()
+            .map(|frame| Image::from_grid(frame, scale, Cell::color))
+            .collect();
+        write_output(&gif, encode_gif(&images, delay)?)?;
+        println!("wrote {} frames to {}", images.len(), gif.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use adventofcode25::{
+        example_path, read_input, solve_part1, solve_part2, verify_part1, verify_part2,
+    };
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day04>(example_path(DAY))?, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day04>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day04>(example_path(DAY))?, 43);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day04>()
+    }
+
+    /// The original approach: rescan the whole grid every round.
+    fn rescan_rounds(grid: &Grid<u8>) -> Vec<Vec<Pos>> {
+        let mut grid = grid.clone();
+        let mut rounds = Vec::new();
+        loop {
+            let positions = find_positions(&grid, MAX_SURROUNDING);
+            if positions.is_empty() {
+                return rounds;
+            }
+            for &pos in &positions {
+                grid[pos] = 0;
+            }
+            rounds.push(positions);
+        }
+    }
+
+    #[test]
+    fn rounds_of_the_example() -> Result<()> {
+        let example = Day04::parse(&read_input(example_path(DAY))?)?;
+        let counts: Vec<usize> = removal_rounds(&example).iter().map(Vec::len).collect();
+        assert_eq!(counts, [13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn queue_matches_rescanning_on_random_grids() {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        for _ in 0..200 {
+            let width = 1 + rng.below(25) as usize;
+            let height = 1 + rng.below(25) as usize;
+            // Mostly dense grids, so the removal takes many rounds.
+            let density = 50 + rng.below(50);
+            let mut grid = Grid::new(width, height, 0u8);
+            for pos in grid.positions() {
+                grid[pos] = u8::from(rng.below(100) < density);
+            }
+            assert_eq!(removal_rounds(&grid), rescan_rounds(&grid), "{grid:?}");
+        }
+    }
+
+    #[test]
+    fn frames_mark_each_round() -> Result<()> {
+        let grid = Day04::parse("@@@\n@@@\n")?;
+        let frames: Vec<String> = removal_frames(&grid).iter().map(Grid::to_string).collect();
+        assert_eq!(frames, ["@@@\n@@@\n", "x@x\nx@x\n", ".x.\n.x.\n"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,448 @@
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use adventofcode25::parse::{Line, sections};
+use adventofcode25::{AocError, InputSource, IntervalSet, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 5;
+
+pub struct Day05;
+
+pub struct Input {
+    /// The ranges as listed, for diagnostics.
+    ranges: Vec<FreshRange>,
+    fresh: IntervalSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+/// A fresh ID range as written in the input, with the line it is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreshRange {
+    pub line: usize,
+    pub start: u64,
+    pub end: u64,
+}
+
+impl FreshRange {
+    fn contains(&self, id: u64) -> bool {
+        self.start <= id && id <= self.end
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = DAY;
+
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "stream",
+            usage: "[--quiet]: classify ingredient IDs as they are read, printing each verdict and the running totals",
+            run: stream,
+        },
+        Tool {
+            name: "diagnose",
+            usage: "[--json]: the ranges covering each ingredient, redundant ranges, clusters and gaps",
+            run: diagnose_tool,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input> {
+        let (ranges, ingredients) = match sections(input)[..] {
+            [] => (Vec::new(), Vec::new()),
+            [ranges] => (ranges.records(parse_numbered_range)?, Vec::new()),
+            [ranges, ingredients] => (
+                ranges.records(parse_numbered_range)?,
+                ingredients.records(|line| line.parse(line.text.trim()))?,
+            ),
+            [_, _, extra, ..] => {
+                return Err(AocError::shape(format!(
+                    "expected ranges and ingredients, found a third section at line {}",
+                    extra.first_line
+                )));
+            }
+        };
+        Ok(Input {
+            fresh: ranges.iter().map(|r| (r.start, r.end)).collect(),
+            ranges,
+            ingredients,
+        })
+    }
+
+    fn part1(input: &Input) -> u32 {
+        input
+            .ingredients
+            .iter()
+            .filter(|&&id| input.fresh.contains(id))
+            .count() as u32
+    }
+
+    fn part2(input: &Input) -> u64 {
+        // At most 2^64 IDs, and only the full range of `u64` would not fit.
+        input.fresh.total_len() as u64
+    }
+}
+
+fn parse_range(line: Line<'_>) -> Result<(u64, u64)> {
+    let (start, end) = line.split_pair("-")?;
+    Ok((line.parse(start)?, line.parse(end)?))
+}
+
+fn parse_numbered_range(line: Line<'_>) -> Result<FreshRange> {
+    let (start, end) = parse_range(line)?;
+    Ok(FreshRange {
+        line: line.number,
+        start,
+        end,
+    })
+}
+
+/// Running counts of a streamed classification.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Tally {
+    pub seen: u64,
+    pub fresh: u64,
+}
+
+/// Reads the ranges from the first section of `reader`, then classifies the ingredient IDs of
+/// the second one line by line as they arrive, calling `verdict` with each ID, whether it is
+/// fresh and the tally so far.
+///
+/// Only the merged ranges and the current line are held in memory, so the ingredient list can
+/// be arbitrarily long. Returns the final tally, which for a whole input matches part 1.
+/// Errors while reading, including lines that are not UTF-8, name `path` as the input.
+pub fn classify_stream(
+    mut reader: impl BufRead,
+    path: &Path,
+    mut verdict: impl FnMut(u64, bool, Tally) -> Result<()>,
+) -> Result<Tally> {
+    let mut fresh = IntervalSet::new();
+    let mut tally = Tally::default();
+    // The section being read: 0 before the ranges, 1 for ranges, 2 for ingredients.
+    let mut section = 0;
+    let mut after_blank = false;
+    let mut text = String::new();
+    for number in 1.. {
+        text.clear();
+        let read = reader
+            .read_line(&mut text)
+            .map_err(|source| AocError::MissingInput {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        if read == 0 {
+            break;
+        }
+        let line = Line {
+            number,
+            text: text.trim_end_matches(['\n', '\r']),
+        };
+        if line.text.trim().is_empty() {
+            after_blank = true;
+            continue;
+        }
+        if section == 0 || after_blank {
+            section += 1;
+            after_blank = false;
+        }
+        match section {
+            1 => {
+                let (start, end) = parse_range(line)?;
+                fresh.insert(start, end);
+            }
+            2 => {
+                let id = line.parse(line.text.trim())?;
+                let is_fresh = fresh.contains(id);
+                tally.seen += 1;
+                tally.fresh += u64::from(is_fresh);
+                verdict(id, is_fresh, tally)?;
+            }
+            _ => {
+                return Err(AocError::shape(format!(
+                    "expected ranges and ingredients, found a third section at line {number}"
+                )));
+            }
+        }
+    }
+    Ok(tally)
+}
+
+fn stream(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--quiet"], &[])?;
+    let quiet = args.flag("--quiet");
+    let mut out = BufWriter::new(io::stdout().lock());
+    let write_error = |source| AocError::Write {
+        path: PathBuf::from("-"),
+        source,
+    };
+    let path = source.path(DAY).unwrap_or_else(|| PathBuf::from("-"));
+    let tally = classify_stream(source.reader(DAY)?, &path, |id, is_fresh, tally| {
+        if quiet {
+            return Ok(());
+        }
+        let verdict = if is_fresh { "fresh" } else { "spoiled" };
+        writeln!(out, "{id} {verdict} {}/{}", tally.fresh, tally.seen).map_err(write_error)
+    })?;
+    writeln!(
+        out,
+        "{} of {} ingredients are fresh",
+        tally.fresh, tally.seen
+    )
+    .map_err(write_error)?;
+    out.flush().map_err(write_error)
+}
+
+/// Where each ingredient's freshness comes from and how the ranges relate to each other.
+/// Ranges are referred to by their line number in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// Every ingredient with the lines of all ranges containing it, empty when it is spoiled.
+    pub covering: Vec<(u64, Vec<usize>)>,
+    /// Ranges fully inside another one, as `(line, containing line)`. Of two identical
+    /// ranges, the later one is reported as contained in the earlier.
+    pub contained: Vec<(usize, usize)>,
+    /// Groups of two or more ranges that overlap or touch, each with the merged range it forms.
+    pub clusters: Vec<Cluster>,
+    /// The IDs between consecutive merged ranges that no range covers.
+    pub gaps: Vec<(u64, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cluster {
+    pub start: u64,
+    pub end: u64,
+    pub lines: Vec<usize>,
+}
+
+pub fn diagnose(input: &Input) -> Diagnostics {
+    let merged = input.fresh.ranges();
+    // The ranges making up each merged range, in input order.
+    let mut members: Vec<Vec<&FreshRange>> = vec![Vec::new(); merged.len()];
+    for range in input.ranges.iter().filter(|r| r.start <= r.end) {
+        let index = merged.partition_point(|&(_, end)| end < range.start);
+        members[index].push(range);
+    }
+
+    let covering = input
+        .ingredients
+        .iter()
+        .map(|&id| {
+            let index = merged.partition_point(|&(_, end)| end < id);
+            let lines = members
+                .get(index)
+                .into_iter()
+                .flatten()
+                .filter(|range| range.contains(id))
+                .map(|range| range.line)
+                .collect();
+            (id, lines)
+        })
+        .collect();
+
+    // Sorted by start, longest first, a range is contained in another exactly when an earlier
+    // range reaches at least as far; the one reaching furthest is reported.
+    let mut by_start: Vec<&FreshRange> = input.ranges.iter().filter(|r| r.start <= r.end).collect();
+    by_start.sort_by_key(|r| (r.start, std::cmp::Reverse(r.end), r.line));
+    let mut contained = Vec::new();
+    let mut furthest: Option<&FreshRange> = None;
+    for range in by_start {
+        match furthest {
+            Some(outer) if range.end <= outer.end => contained.push((range.line, outer.line)),
+            _ => furthest = Some(range),
+        }
+    }
+    contained.sort_unstable();
+
+    let clusters = merged
+        .iter()
+        .zip(&members)
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(&(start, end), members)| Cluster {
+            start,
+            end,
+            lines: members.iter().map(|range| range.line).collect(),
+        })
+        .collect();
+
+    let gaps = merged
+        .windows(2)
+        .map(|pair| (pair[0].1 + 1, pair[1].0 - 1))
+        .collect();
+
+    Diagnostics {
+        covering,
+        contained,
+        clusters,
+        gaps,
+    }
+}
+
+impl Diagnostics {
+    pub fn table(&self) -> String {
+        let lines = |lines: &[usize]| -> String {
+            if lines.is_empty() {
+                return "-".to_string();
+            }
+            let lines: Vec<String> = lines.iter().map(usize::to_string).collect();
+            lines.join(", ")
+        };
+        let mut table = format!("{:<20}  covered by lines\n", "ingredient");
+        for (id, covering) in &self.covering {
+            table.push_str(&format!("{id:<20}  {}\n", lines(covering)));
+        }
+        table.push_str(&format!("\n{:<20}  contained in line\n", "range on line"));
+        for (line, outer) in &self.contained {
+            table.push_str(&format!("{line:<20}  {outer}\n"));
+        }
+        table.push_str(&format!("\n{:<41}  lines\n", "cluster"));
+        for cluster in &self.clusters {
+            let range = format!("{}-{}", cluster.start, cluster.end);
+            table.push_str(&format!("{range:<41}  {}\n", lines(&cluster.lines)));
+        }
+        table.push_str(&format!("\n{:<41}  IDs\n", "gap"));
+        for &(start, end) in &self.gaps {
+            let range = format!("{start}-{end}");
+            table.push_str(&format!("{range:<41}  {}\n", end - start + 1));
+        }
+        table
+    }
+
+    pub fn json(&self) -> String {
+        let list = |items: Vec<String>| format!("[{}]", items.join(", "));
+        let lines = |lines: &[usize]| list(lines.iter().map(usize::to_string).collect());
+        let covering = self
+            .covering
+            .iter()
+            .map(|(id, covering)| format!("{{\"id\": {id}, \"lines\": {}}}", lines(covering)))
+            .collect();
+        let contained = self
+            .contained
+            .iter()
+            .map(|(line, outer)| format!("{{\"line\": {line}, \"within\": {outer}}}"))
+            .collect();
+        let clusters = self
+            .clusters
+            .iter()
+            .map(|c| {
+                format!(
+                    "{{\"start\": {}, \"end\": {}, \"lines\": {}}}",
+                    c.start,
+                    c.end,
+                    lines(&c.lines)
+                )
+            })
+            .collect();
+        let gaps = self
+            .gaps
+            .iter()
+            .map(|(start, end)| format!("{{\"start\": {start}, \"end\": {end}}}"))
+            .collect();
+        format!(
+            "{{\"ingredients\": {}, \"contained\": {}, \"clusters\": {}, \"gaps\": {}}}\n",
+            list(covering),
+            list(contained),
+            list(clusters),
+            list(gaps)
+        )
+    }
+}
+
+fn diagnose_tool(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--json"], &[])?;
+    let diagnostics = diagnose(&Day05::parse(&source.read(DAY)?)?);
+    if args.flag("--json") {
+        print!("{}", diagnostics.json());
+    } else {
+        print!("{}", diagnostics.table());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{
+        example_path, read_input, solve_part1, solve_part2, verify_part1, verify_part2,
+    };
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day05>(example_path(DAY))?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day05>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day05>(example_path(DAY))?, 14);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day05>()
+    }
+
+    #[test]
+    fn streaming_matches_part1() -> Result<()> {
+        let input = read_input(example_path(DAY))?;
+        let mut verdicts = Vec::new();
+        let tally = classify_stream(input.as_bytes(), &example_path(DAY), |id, is_fresh, _| {
+            verdicts.push((id, is_fresh));
+            Ok(())
+        })?;
+        let parsed = Day05::parse(&input)?;
+        assert_eq!(tally.fresh, u64::from(Day05::part1(&parsed)));
+        assert_eq!(tally.seen, parsed.ingredients.len() as u64);
+        assert_eq!(verdicts.len(), parsed.ingredients.len());
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_reports_malformed_input() {
+        let path = Path::new("ids.txt");
+        let stream = |input: &[u8]| classify_stream(input, path, |_, _, _| Ok(()));
+        let third = stream(b"1-2\n\n1\n\n3-4\n");
+        assert!(matches!(third, Err(AocError::UnsupportedShape(_))));
+        match stream(b"1-2\n\nx\n") {
+            Err(AocError::Parse { line, .. }) => assert_eq!(line, 3),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        match stream(b"1-2\n\n\xff\n") {
+            Err(AocError::MissingInput { path, .. }) => assert_eq!(path, Path::new("ids.txt")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn diagnostics_name_ranges_by_line() -> Result<()> {
+        let input = Day05::parse("3-5\n10-14\n16-20\n12-18\n11-13\n30-30\n11-13\n\n1\n12\n30\n")?;
+        let diagnostics = diagnose(&input);
+        assert_eq!(
+            diagnostics.covering,
+            [(1, vec![]), (12, vec![2, 4, 5, 7]), (30, vec![6])]
+        );
+        assert_eq!(diagnostics.contained, [(5, 2), (7, 2)]);
+        assert_eq!(
+            diagnostics.clusters,
+            [Cluster {
+                start: 10,
+                end: 20,
+                lines: vec![2, 3, 4, 5, 7]
+            }]
+        );
+        assert_eq!(diagnostics.gaps, [(6, 9), (21, 29)]);
+        assert!(
+            diagnostics
+                .json()
+                .starts_with("{\"ingredients\": [{\"id\": 1, \"lines\": []}, ")
+        );
+        Ok(())
+    }
+}
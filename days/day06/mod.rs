@@ -0,0 +1,750 @@
+use std::ops::Range;
+
+use adventofcode25::{AocError, Grid, InputSource, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+/// An arithmetic operation, applied left to right when given more than two operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Max,
+    Min,
+}
+
+impl Op {
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Subtract),
+            "*" => Some(Self::Multiply),
+            "/" => Some(Self::Divide),
+            "max" => Some(Self::Max),
+            "min" => Some(Self::Min),
+            _ => None,
+        }
+    }
+
+    fn apply(self, a: i128, b: i128) -> std::result::Result<i128, String> {
+        let result = match self {
+            Self::Add => a.checked_add(b),
+            Self::Subtract => a.checked_sub(b),
+            Self::Multiply => a.checked_mul(b),
+            Self::Divide if b == 0 => return Err(format!("{a} / 0 divides by zero")),
+            Self::Divide => Some(a / b),
+            Self::Max => Some(a.max(b)),
+            Self::Min => Some(a.min(b)),
+        };
+        result.ok_or_else(|| format!("{a} {} {b} does not fit an i128", self.symbol()))
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Max => "max",
+            Self::Min => "min",
+        }
+    }
+
+    /// `values` combined with this operation, written out: `1 + 2 + 3` or `max(1, 2, 3)`.
+    fn describe(self, values: &[String]) -> String {
+        match self {
+            Self::Max | Self::Min => format!("{}({})", self.symbol(), values.join(", ")),
+            _ => values.join(&format!(" {} ", self.symbol())),
+        }
+    }
+
+    fn fold(self, values: &[i128]) -> std::result::Result<i128, String> {
+        let (&first, rest) = values
+            .split_first()
+            .ok_or("no operands to apply the operator to")?;
+        rest.iter()
+            .try_fold(first, |acc, &value| self.apply(acc, value))
+    }
+}
+
+/// An expression written below a problem. `_` stands for the next operand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Operand(usize),
+    Apply(Op, Vec<Expr>),
+}
+
+impl Expr {
+    /// The expression with `operand` written in for each placeholder. Nested arithmetic is
+    /// parenthesised.
+    fn describe(&self, operand: &impl Fn(usize) -> String) -> String {
+        match self {
+            Expr::Operand(index) => operand(*index),
+            Expr::Apply(op, args) => {
+                let args: Vec<String> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        Expr::Apply(inner, _)
+                            if !matches!(op, Op::Max | Op::Min)
+                                && !matches!(inner, Op::Max | Op::Min) =>
+                        {
+                            format!("({})", arg.describe(operand))
+                        }
+                        _ => arg.describe(operand),
+                    })
+                    .collect();
+                op.describe(&args)
+            }
+        }
+    }
+
+    fn evaluate(&self, operands: &[i128]) -> std::result::Result<i128, String> {
+        match self {
+            Expr::Operand(index) => Ok(operands[*index]),
+            Expr::Apply(op, args) => {
+                let values = args
+                    .iter()
+                    .map(|arg| arg.evaluate(operands))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                op.fold(&values)
+            }
+        }
+    }
+}
+
+/// What to do with a problem's operands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operator {
+    /// A bare operator such as `+` or `max`, applied across all operands.
+    Each(Op),
+    /// A parenthesised expression such as `(_+_)*_`, taking a fixed number of operands.
+    Expression { expr: Expr, operands: usize },
+}
+
+impl Operator {
+    /// The calculation this operator makes of `operands`, such as `(100 + 30) * 8`.
+    pub fn describe(&self, operands: &[i128]) -> String {
+        let values: Vec<String> = operands.iter().map(i128::to_string).collect();
+        match self {
+            Operator::Each(op) => op.describe(&values),
+            Operator::Expression { expr, operands: n } if *n == operands.len() => {
+                expr.describe(&|index| values[index].clone())
+            }
+            Operator::Expression { expr, .. } => format!(
+                "{} of {}",
+                expr.describe(&|_| "_".to_string()),
+                values.join(", ")
+            ),
+        }
+    }
+
+    pub fn apply(&self, operands: &[i128]) -> std::result::Result<i128, String> {
+        match self {
+            Operator::Each(op) => op.fold(operands),
+            Operator::Expression { expr, operands: n } if *n == operands.len() => {
+                expr.evaluate(operands)
+            }
+            Operator::Expression { operands: n, .. } => Err(format!(
+                "the expression takes {n} operands, but {} were read",
+                operands.len()
+            )),
+        }
+    }
+}
+
+/// The two ways the operands of a problem can be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// One operand per row, as in part 1.
+    Rows,
+    /// One operand per column, right to left, with digits read top to bottom, as in part 2.
+    Columns,
+}
+
+/// One problem of the worksheet: a block of columns between fully blank ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The columns of the grid the problem occupies.
+    pub columns: Range<usize>,
+    pub operator: Operator,
+    pub operands_rowwise: Vec<i128>,
+    pub operands_columnwise: Vec<i128>,
+}
+
+impl Problem {
+    pub fn operands(&self, reading: Reading) -> &[i128] {
+        match reading {
+            Reading::Rows => &self.operands_rowwise,
+            Reading::Columns => &self.operands_columnwise,
+        }
+    }
+
+    pub fn solve(&self, reading: Reading) -> std::result::Result<i128, String> {
+        self.operator.apply(self.operands(reading))
+    }
+}
+
+/// The parsed worksheet: operand rows above a row of operators, split into problems.
+pub struct Worksheet {
+    pub grid: Grid<char>,
+    pub problems: Vec<Problem>,
+}
+
+impl Worksheet {
+    pub fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::from_str_padded(input, ' ', Some)?;
+        let height = grid.height();
+        if height < 2 {
+            return Err(AocError::shape(
+                "worksheet needs at least one operand row and an operator row",
+            ));
+        }
+
+        for (row, line) in grid.rows().take(height - 1).enumerate() {
+            if let Some(col) = line.iter().position(|&c| c != ' ' && !c.is_ascii_digit()) {
+                return Err(AocError::parse(
+                    row + 1,
+                    col + 1,
+                    format!(
+                        "problem {}: expected a digit, found {:?}",
+                        problem_at(&grid, col),
+                        line[col]
+                    ),
+                ));
+            }
+        }
+
+        let problems = find_problem_ranges(&grid)
+            .into_iter()
+            .enumerate()
+            .map(|(index, columns)| parse_problem(&grid, index + 1, columns))
+            .collect::<Result<_>>()?;
+        Ok(Worksheet { grid, problems })
+    }
+
+    /// The sum of all problem results in `reading`, or the first problem that cannot be solved.
+    pub fn total(&self, reading: Reading) -> Result<i128> {
+        let mut total = 0i128;
+        for (index, problem) in self.problems.iter().enumerate() {
+            let located = |message: String| {
+                AocError::shape(format!(
+                    "problem {} at column {}, read by {reading:?}: {message}",
+                    index + 1,
+                    problem.columns.start + 1
+                ))
+            };
+            let result = problem.solve(reading).map_err(located)?;
+            total = total
+                .checked_add(result)
+                .ok_or_else(|| located("the total does not fit an i128".to_string()))?;
+        }
+        Ok(total)
+    }
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = DAY;
+
+    type Input = Worksheet;
+    type Answer1 = Result<i128>;
+    type Answer2 = Result<i128>;
+
+    const TOOLS: &'static [Tool] = &[Tool {
+        name: "render",
+        usage: "[--rows|--columns]: the worksheet with problems bracketed, operands in reading order and results",
+        run: render_tool,
+    }];
+
+    fn parse(input: &str) -> Result<Worksheet> {
+        Worksheet::parse(input)
+    }
+
+    fn part1(worksheet: &Worksheet) -> Result<i128> {
+        worksheet.total(Reading::Rows)
+    }
+
+    fn part2(worksheet: &Worksheet) -> Result<i128> {
+        worksheet.total(Reading::Columns)
+    }
+}
+
+/// The columns of each problem: runs of columns between fully blank ones. A blank column inside
+/// parentheses of the operator row, as in `max(_, _)`, belongs to the expression instead.
+fn find_problem_ranges(grid: &Grid<char>) -> Vec<Range<usize>> {
+    let width = grid.width();
+    let operator_row = grid.row(grid.height() - 1);
+    let mut ranges = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+
+    for (col, operator) in operator_row.iter().enumerate() {
+        let is_separator = depth == 0 && grid.column(col).all(|&c| c == ' ');
+        match operator {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        match (start, is_separator) {
+            (None, false) => start = Some(col),
+            (Some(s), true) => {
+                ranges.push(s..col);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        ranges.push(s..width);
+    }
+    ranges
+}
+
+/// The 1-based number of the problem that column `col` belongs to, counting blank columns as
+/// part of the problem to their left.
+fn problem_at(grid: &Grid<char>, col: usize) -> usize {
+    find_problem_ranges(grid)
+        .iter()
+        .take_while(|range| range.start <= col)
+        .count()
+        .max(1)
+}
+
+fn parse_problem(grid: &Grid<char>, number: usize, columns: Range<usize>) -> Result<Problem> {
+    let operator_row = grid.height() - 1;
+    let error = |row: usize, col: usize, message: String| {
+        AocError::parse(row + 1, col + 1, format!("problem {number}: {message}"))
+    };
+    let operand = |digits: String, row: usize, col: usize| {
+        digits
+            .parse::<i128>()
+            .map_err(|_| error(row, col, format!("{digits} does not fit an i128")))
+    };
+
+    let mut operands_rowwise = Vec::new();
+    for row in 0..operator_row {
+        let cells = &grid.row(row)[columns.clone()];
+        let digits: String = cells.iter().filter(|&&c| c != ' ').collect();
+        if !digits.is_empty() {
+            let first = columns.start + cells.iter().position(|&c| c != ' ').unwrap_or(0);
+            operands_rowwise.push(operand(digits, row, first)?);
+        }
+    }
+
+    let mut operands_columnwise = Vec::new();
+    for col in columns.clone().rev() {
+        let digits: String = grid
+            .column(col)
+            .take(operator_row)
+            .filter(|&&c| c != ' ')
+            .collect();
+        if !digits.is_empty() {
+            operands_columnwise.push(operand(digits, 0, col)?);
+        }
+    }
+
+    let text: String = grid.row(operator_row)[columns.clone()].iter().collect();
+    let operator = parse_operator(&text)
+        .map_err(|(offset, message)| error(operator_row, columns.start + offset, message))?;
+
+    Ok(Problem {
+        columns,
+        operator,
+        operands_rowwise,
+        operands_columnwise,
+    })
+}
+
+/// Parses the text below a problem, reporting errors with their offset into `text`.
+///
+/// Either a bare operator, or an expression over `_` placeholders with `+ - * /` (the usual
+/// precedence, left to right), parentheses and `max(...)`/`min(...)` taking any number of
+/// arguments.
+fn parse_operator(text: &str) -> std::result::Result<Operator, (usize, String)> {
+    let tokens = tokenize(text)?;
+    match tokens[..] {
+        [] => Err((0, "expected an operator below the problem".to_string())),
+        [(_, token)] if Op::from_token(token).is_some() => {
+            Ok(Operator::Each(Op::from_token(token).unwrap()))
+        }
+        _ => {
+            let mut parser = ExprParser {
+                tokens: &tokens,
+                next: 0,
+                operands: 0,
+                end: text.len(),
+            };
+            let expr = parser.sum()?;
+            if let Some(&(offset, token)) = tokens.get(parser.next) {
+                return Err((offset, format!("unexpected {token:?}")));
+            }
+            Ok(Operator::Expression {
+                expr,
+                operands: parser.operands,
+            })
+        }
+    }
+}
+
+/// Splits operator text into tokens with their byte offsets.
+fn tokenize(text: &str) -> std::result::Result<Vec<(usize, &str)>, (usize, String)> {
+    let mut tokens = Vec::new();
+    let mut rest = text.char_indices().peekable();
+    while let Some((offset, c)) = rest.next() {
+        match c {
+            ' ' => {}
+            '_' | '(' | ')' | ',' | '+' | '-' | '*' | '/' => {
+                tokens.push((offset, &text[offset..offset + 1]));
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut end = offset + 1;
+                while let Some(&(next, c)) = rest.peek()
+                    && c.is_ascii_alphabetic()
+                {
+                    end = next + 1;
+                    rest.next();
+                }
+                tokens.push((offset, &text[offset..end]));
+            }
+            _ => return Err((offset, format!("unexpected {c:?} in the operator"))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser over the tokens of an operator expression.
+struct ExprParser<'a> {
+    tokens: &'a [(usize, &'a str)],
+    next: usize,
+    /// The number of `_` placeholders seen so far.
+    operands: usize,
+    /// The offset reported for errors at the end of the text.
+    end: usize,
+}
+
+impl ExprParser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.next).map(|&(_, token)| token)
+    }
+
+    fn expect(&mut self, expected: &str) -> std::result::Result<(), (usize, String)> {
+        match self.tokens.get(self.next) {
+            Some(&(_, token)) if token == expected => {
+                self.next += 1;
+                Ok(())
+            }
+            Some(&(offset, token)) => {
+                Err((offset, format!("expected {expected:?}, found {token:?}")))
+            }
+            None => Err((self.end, format!("expected {expected:?}"))),
+        }
+    }
+
+    /// Left-associative binary operators on one precedence level.
+    fn binary(
+        &mut self,
+        ops: &[&str],
+        operand: fn(&mut Self) -> std::result::Result<Expr, (usize, String)>,
+    ) -> std::result::Result<Expr, (usize, String)> {
+        let mut expr = operand(self)?;
+        while let Some(token) = self.peek()
+            && ops.contains(&token)
+        {
+            let op = Op::from_token(token).unwrap();
+            self.next += 1;
+            expr = Expr::Apply(op, vec![expr, operand(self)?]);
+        }
+        Ok(expr)
+    }
+
+    fn sum(&mut self) -> std::result::Result<Expr, (usize, String)> {
+        self.binary(&["+", "-"], Self::product)
+    }
+
+    fn product(&mut self) -> std::result::Result<Expr, (usize, String)> {
+        self.binary(&["*", "/"], Self::atom)
+    }
+
+    fn atom(&mut self) -> std::result::Result<Expr, (usize, String)> {
+        let Some(&(offset, token)) = self.tokens.get(self.next) else {
+            return Err((self.end, "expected an operand".to_string()));
+        };
+        self.next += 1;
+        match token {
+            "_" => {
+                self.operands += 1;
+                Ok(Expr::Operand(self.operands - 1))
+            }
+            "(" => {
+                let expr = self.sum()?;
+                self.expect(")")?;
+                Ok(expr)
+            }
+            "max" | "min" => {
+                self.expect("(")?;
+                let mut args = vec![self.sum()?];
+                while self.peek() == Some(",") {
+                    self.next += 1;
+                    args.push(self.sum()?);
+                }
+                self.expect(")")?;
+                Ok(Expr::Apply(Op::from_token(token).unwrap(), args))
+            }
+            _ => Err((offset, format!("expected an operand, found {token:?}"))),
+        }
+    }
+}
+
+/// The worksheet grid with every problem in brackets and the operand order of `reading`
+/// marked, followed by each problem's calculation and result (or why it has none) and the total.
+///
+/// Read by rows, the gutter numbers the operand rows. Read by columns, the line above the grid
+/// numbers each problem's columns in the order they are read: right to left, every column a
+/// number written top to bottom.
+pub fn render(worksheet: &Worksheet, reading: Reading) -> String {
+    let grid = &worksheet.grid;
+    let operator_row = grid.height() - 1;
+    let problem_of = |col: usize| {
+        worksheet
+            .problems
+            .iter()
+            .position(|problem| problem.columns.contains(&col))
+    };
+    // One line of the grid, with `cell` drawing each column and brackets around each problem.
+    let bracketed = |gutter: &str, cell: &dyn Fn(usize) -> char, brackets: (char, char)| {
+        let mut line = format!("{gutter:<4}");
+        for col in 0..grid.width() {
+            let problem = problem_of(col).map(|index| &worksheet.problems[index]);
+            if problem.is_some_and(|p| p.columns.start == col) {
+                line.push(brackets.0);
+            }
+            line.push(cell(col));
+            if problem.is_some_and(|p| p.columns.end == col + 1) {
+                line.push(brackets.1);
+            }
+        }
+        line.trim_end().to_string() + "\n"
+    };
+
+    let mut out = String::new();
+    match reading {
+        Reading::Rows => {
+            out.push_str("read by rows (part 1): one operand per row\n");
+        }
+        Reading::Columns => {
+            out.push_str(
+                "read by columns (part 2): one operand per column, right to left, digits top to bottom\n",
+            );
+            // Each problem's non-blank columns numbered in reading order.
+            let order = |col: usize| {
+                let Some(problem) = problem_of(col).map(|index| &worksheet.problems[index]) else {
+                    return ' ';
+                };
+                let has_digits = |c: usize| grid.column(c).take(operator_row).any(|&d| d != ' ');
+                if !has_digits(col) {
+                    return ' ';
+                }
+                let position = (col + 1..problem.columns.end)
+                    .filter(|&c| has_digits(c))
+                    .count();
+                char::from_digit((position as u32 + 1) % 10, 10).unwrap()
+            };
+            out.push_str(&bracketed("", &order, (' ', ' ')));
+        }
+    }
+    for row in 0..grid.height() {
+        let gutter = match (reading, row == operator_row) {
+            (_, true) => "op".to_string(),
+            (Reading::Rows, false) if grid.row(row).iter().any(|&c| c != ' ') => {
+                (row + 1).to_string()
+            }
+            _ => String::new(),
+        };
+        out.push_str(&bracketed(&gutter, &|col| grid[(row, col)], ('[', ']')));
+    }
+
+    for (index, problem) in worksheet.problems.iter().enumerate() {
+        let operands = problem.operands(reading);
+        let result = match problem.solve(reading) {
+            Ok(result) => result.to_string(),
+            Err(message) => format!("error: {message}"),
+        };
+        out.push_str(&format!(
+            "problem {}, columns {}-{}: {} = {result}\n",
+            index + 1,
+            problem.columns.start + 1,
+            problem.columns.end,
+            problem.operator.describe(operands)
+        ));
+    }
+    match worksheet.total(reading) {
+        Ok(total) => out.push_str(&format!("total {total}\n")),
+        Err(error) => out.push_str(&format!("total: error: {error}\n")),
+    }
+    out
+}
+
+fn render_tool(source: &InputSource, args: &[String]) -> Result<()> {
+    let args = ToolArgs::parse(args, &["--rows", "--columns"], &[])?;
+    let worksheet = Worksheet::parse(&source.read(DAY)?)?;
+    let readings = match (args.flag("--rows"), args.flag("--columns")) {
+        (true, false) => vec![Reading::Rows],
+        (false, true) => vec![Reading::Columns],
+        _ => vec![Reading::Rows, Reading::Columns],
+    };
+    let rendered: Vec<String> = readings
+        .into_iter()
+        .map(|reading| render(&worksheet, reading))
+        .collect();
+    print!("{}", rendered.join("\n"));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day06>(example_path(DAY))?, 4277556);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day06>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day06>(example_path(DAY))?, 3263827);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day06>()
+    }
+
+    #[test]
+    fn operators_and_expressions() -> Result<()> {
+        let worksheet = Worksheet::parse(
+            "20 8 9   100    \n 4 2 3   30     \n 1 2 12  8      \n-  / max (_+_)*_\n",
+        )?;
+        let results: Vec<i128> = worksheet
+            .problems
+            .iter()
+            .map(|problem| problem.solve(Reading::Rows).unwrap())
+            .collect();
+        assert_eq!(results, [20 - 4 - 1, 8 / 2 / 2, 12, (100 + 30) * 8]);
+        assert_eq!(worksheet.problems[0].operands_columnwise, [41, 2]);
+        assert_eq!(
+            worksheet.problems[3].operator,
+            Operator::Expression {
+                expr: Expr::Apply(
+                    Op::Multiply,
+                    vec![
+                        Expr::Apply(Op::Add, vec![Expr::Operand(0), Expr::Operand(1)]),
+                        Expr::Operand(2),
+                    ]
+                ),
+                operands: 3
+            }
+        );
+        assert_eq!(
+            parse_operator("max(_, min(_,_))").map(|op| op.apply(&[1, 5, 3])),
+            Ok(Ok(3))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_name_the_problem_and_column() -> Result<()> {
+        let error = |input: &str| match Worksheet::parse(input) {
+            Err(AocError::Parse {
+                line,
+                column,
+                message,
+            }) => (line, column, message),
+            Err(other) => (0, 0, other.to_string()),
+            Ok(_) => panic!("{input:?} parsed"),
+        };
+        let unsolved = |input: &str, reading: Reading| match Worksheet::parse(input)?.total(reading)
+        {
+            Err(error) => Ok(error.to_string()),
+            Ok(total) => panic!("{input:?} totals {total}"),
+        };
+        let (line, column, message) = error("1 2\n+ %\n");
+        assert_eq!((line, column), (2, 3));
+        assert!(message.starts_with("problem 2:"), "{message}");
+        // The open parenthesis keeps `(_+ *` together, so the `*` is where an operand is missing.
+        let (line, column, message) = error("1 2 3\n+ (_+ *\n");
+        assert_eq!((line, column), (2, 7));
+        assert!(message.starts_with("problem 2:"), "{message}");
+        let (_, _, message) = error("1 2\n+ /\n0 0\n");
+        assert!(message.contains("problem 1"), "{message}");
+        let message = unsolved("1 04\n+ / \n", Reading::Columns)?;
+        assert!(message.contains("problem 2 at column 3"), "{message}");
+        let message = unsolved(
+            "99999999999999999999\n99999999999999999999\n*\n",
+            Reading::Rows,
+        )?;
+        assert!(message.contains("does not fit"), "{message}");
+        // Subtraction may go below zero, and each reading only fails on its own operands.
+        let worksheet = Worksheet::parse("1 104\n5 2  \n- _-_\n")?;
+        assert_eq!(worksheet.total(Reading::Rows)?, -4 + 102);
+        let message = unsolved("1 104\n5 2  \n- _-_\n", Reading::Columns)?;
+        // Spaces inside parentheses don't split the expression, even below blank columns.
+        let worksheet = Worksheet::parse("12 5      \n3  6      \n+  max(_, _)\n")?;
+        assert_eq!(worksheet.problems[1].columns, 3..12);
+        assert_eq!(worksheet.total(Reading::Rows)?, 15 + 6);
+        assert!(
+            message.contains("takes 2 operands, but 3 were read"),
+            "{message}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn renders_both_readings() -> Result<()> {
+        let worksheet = Worksheet::parse("12 64\n 3 23\n+  (_*_)\n")?;
+        assert_eq!(
+            render(&worksheet, Reading::Rows),
+            "read by rows (part 1): one operand per row\n\
+             1   [12] [64   ]\n\
+             2   [ 3] [23   ]\n\
+             op  [+ ] [(_*_)]\n\
+             problem 1, columns 1-2: 12 + 3 = 15\n\
+             problem 2, columns 4-8: 64 * 23 = 1472\n\
+             total 1487\n"
+        );
+        assert_eq!(
+            render(&worksheet, Reading::Columns),
+            "read by columns (part 2): one operand per column, right to left, digits top to bottom\n\
+             \x20    21   21\n\
+             \x20   [12] [64   ]\n\
+             \x20   [ 3] [23   ]\n\
+             op  [+ ] [(_*_)]\n\
+             problem 1, columns 1-2: 23 + 1 = 24\n\
+             problem 2, columns 4-8: 43 * 62 = 2666\n\
+             total 2690\n"
+        );
+        // Unsolvable problems are rendered with their error rather than stopping the rendering.
+        let worksheet = Worksheet::parse("1 04\n+ / \n")?;
+        let rendered = render(&worksheet, Reading::Columns);
+        assert!(
+            rendered.contains("problem 2, columns 3-4: 4 / 0 = error: 4 / 0 divides by zero\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.ends_with("problem 2 at column 3, read by Columns: 4 / 0 divides by zero\n"),
+            "{rendered}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use adventofcode25::{AocError, Grid, InputSource, Pos, Result, Solution, Tool, ToolArgs};
+
+const DAY: u8 = 7;
+
+pub struct Day07;
+
+pub struct Input {
+    grid: Grid<char>,
+    start: Pos,
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = DAY;
+
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const TOOLS: &'static [Tool] = &[Tool {
+        name: "render",
+        usage: "the manifold with the beams drawn in, and the split and timeline counts",
+        run: render_tool,
+    }];
+
+    fn parse(input: &str) -> Result<Input> {
+        let grid = Grid::from_str(input, |c| matches!(c, '.' | '^' | 'S').then_some(c))?;
+        let start = grid
+            .find(&'S')
+            .filter(|&(row, _)| row == 0)
+            .ok_or_else(|| AocError::shape("no start position 'S' on the first row"))?;
+
+        Ok(Input { grid, start })
+    }
+
+    fn part1(input: &Input) -> u64 {
+        splits(input, &beam_counts(input))
+    }
+
+    fn part2(input: &Input) -> u64 {
+        timelines(&beam_counts(input))
+    }
+}
+
+/// For every cell, the number of timelines in which a beam passes through it.
+///
+/// A single pass from the top row down: a beam continues into the cell below, unless that
+/// cell is a splitter, in which case it continues into the cells either side of the splitter
+/// instead. Beams that would leave the manifold at the sides are lost.
+pub fn beam_counts(input: &Input) -> Grid<u64> {
+    let grid = &input.grid;
+    let mut counts = Grid::new(grid.width(), grid.height(), 0u64);
+    counts[input.start] = 1;
+    for row in 1..grid.height() {
+        for col in 0..grid.width() {
+            let beams = counts[(row - 1, col)];
+            if beams == 0 {
+                continue;
+            }
+            if grid[(row, col)] == '^' {
+                for side in [col.checked_sub(1), Some(col + 1)].into_iter().flatten() {
+                    if side < grid.width() {
+                        counts[(row, side)] += beams;
+                    }
+                }
+            } else {
+                counts[(row, col)] += beams;
+            }
+        }
+    }
+    counts
+}
+
+/// The number of splitters reached by a beam.
+fn splits(input: &Input, counts: &Grid<u64>) -> u64 {
+    input
+        .grid
+        .iter()
+        .filter(|&((row, col), &cell)| cell == '^' && row > 0 && counts[(row - 1, col)] > 0)
+        .count() as u64
+}
+
+/// The number of timelines: every beam reaching the bottom row ends one.
+fn timelines(counts: &Grid<u64>) -> u64 {
+    counts.row(counts.height() - 1).iter().sum()
+}
+
+/// The manifold with the beams drawn in as `|`, like the illustrations in the puzzle.
+pub fn render(input: &Input, counts: &Grid<u64>) -> Grid<char> {
+    let mut drawn = input.grid.clone();
+    for (pos, &beams) in counts.iter() {
+        if beams > 0 && drawn[pos] == '.' {
+            drawn[pos] = '|';
+        }
+    }
+    drawn
+}
+
+fn render_tool(source: &InputSource, args: &[String]) -> Result<()> {
+    ToolArgs::parse(args, &[], &[])?;
+    let input = Day07::parse(&source.read(DAY)?)?;
+    let counts = beam_counts(&input);
+    print!("{}", render(&input, &counts));
+    println!(
+        "{} splits, {} timelines",
+        splits(&input, &counts),
+        timelines(&counts)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day07>(example_path(DAY))?, 21);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day07>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day07>(example_path(DAY))?, 40);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day07>()
+    }
+
+    #[test]
+    fn counts_and_renders_beams() -> Result<()> {
+        let input = Day07::parse("..S..\n.....\n..^..\n.....\n.^.^.\n.....\n")?;
+        let counts = beam_counts(&input);
+        assert_eq!(counts.row(5), [1, 0, 2, 0, 1]);
+        assert_eq!((splits(&input, &counts), timelines(&counts)), (3, 4));
+        assert_eq!(
+            render(&input, &counts).to_string(),
+            "..S..\n..|..\n.|^|.\n.|.|.\n|^|^|\n|.|.|\n"
+        );
+        Ok(())
+    }
+}
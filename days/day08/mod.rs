@@ -1,17 +1,40 @@
 use std::collections::HashMap;
 
-use adventofcode25::{input_path, read_lines};
+use adventofcode25::parse::records;
+use adventofcode25::{Result, Solution};
 
 const DAY: u8 = 8;
+const MAX_CONNECTIONS: usize = 1000;
 
-fn main() {
-    let points = parse_input(&input_path(DAY));
-    println!("Part 1: {}", solve_part1(&points, 1000));
-    println!("Part 2: {}", solve_part2(&points));
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Vector3>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        records(input, |line| {
+            let [x, y, z] = line.ints()?[..] else {
+                return Err(line.error(line.text, "expected three coordinates"));
+            };
+            Ok(Vector3 { x, y, z })
+        })
+    }
+
+    fn part1(points: &Self::Input) -> u64 {
+        connect_closest(points, MAX_CONNECTIONS)
+    }
+
+    fn part2(points: &Self::Input) -> u64 {
+        connect_all(points)
+    }
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
-struct Vector3 {
+pub struct Vector3 {
     x: i64,
     y: i64,
     z: i64,
@@ -23,21 +46,6 @@ impl Vector3 {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vector3> {
-    read_lines(input)
-        .expect("Failed to read input file")
-        .map_while(Result::ok)
-        .map(|line| {
-            let coords: Vec<i64> = line.split(',').map(|s| s.parse().unwrap()).collect();
-            Vector3 {
-                x: coords[0],
-                y: coords[1],
-                z: coords[2],
-            }
-        })
-        .collect()
-}
-
 fn compute_sorted_edges(points: &[Vector3]) -> Vec<(Vector3, Vector3, i64)> {
     let mut edges = Vec::new();
     for i in 0..points.len() {
@@ -129,7 +137,7 @@ impl UnionFind {
     }
 }
 
-fn solve_part1(points: &[Vector3], max_connections: usize) -> u64 {
+fn connect_closest(points: &[Vector3], max_connections: usize) -> u64 {
     let edges = compute_sorted_edges(points);
     let mut uf = UnionFind::new(points);
 
@@ -142,7 +150,7 @@ fn solve_part1(points: &[Vector3], max_connections: usize) -> u64 {
     sizes.iter().take(3).product::<usize>() as u64
 }
 
-fn solve_part2(points: &[Vector3]) -> u64 {
+fn connect_all(points: &[Vector3]) -> u64 {
     let edges = compute_sorted_edges(points);
     let mut uf = UnionFind::new(points);
 
@@ -157,29 +165,28 @@ fn solve_part2(points: &[Vector3]) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use adventofcode25::example_path;
+    use adventofcode25::{example_path, read_input, solve_part2, verify_part1, verify_part2};
 
     #[test]
-    fn part1_example() {
-        let points = parse_input(&example_path(DAY));
-        assert_eq!(solve_part1(&points, 10), 40);
+    fn part1_example() -> Result<()> {
+        let points = Day08::parse(&read_input(example_path(DAY))?)?;
+        assert_eq!(connect_closest(&points, 10), 40);
+        Ok(())
     }
 
     #[test]
-    fn part1_real() {
-        let points = parse_input(&input_path(DAY));
-        solve_part1(&points, 1000);
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day08>()
     }
 
     #[test]
-    fn part2_example() {
-        let points = parse_input(&example_path(DAY));
-        assert_eq!(solve_part2(&points), 25272);
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day08>(example_path(DAY))?, 25272);
+        Ok(())
     }
 
     #[test]
-    fn part2_real() {
-        let points = parse_input(&input_path(DAY));
-        solve_part2(&points);
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day08>()
     }
 }
@@ -1,14 +1,55 @@
-use adventofcode25::{input_path, read_lines};
+use adventofcode25::parse::records;
+use adventofcode25::{AocError, Result, Solution};
 
 const DAY: u8 = 9;
 
-fn main() {
-    solve_part1(&input_path(DAY));
-    solve_part2(&input_path(DAY));
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Point>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let points = records(input, |line| {
+            let [x, y] = line.ints()?[..] else {
+                return Err(line.error(line.text, "expected two coordinates"));
+            };
+            Ok(Point { x, y })
+        })?;
+
+        if points.len() < 2 {
+            return Err(AocError::shape("at least two red tiles are needed"));
+        }
+        Ok(points)
+    }
+
+    fn part1(points: &Self::Input) -> u64 {
+        let mut largest_area = 0;
+        for i in 0..points.len() - 1 {
+            let point1 = &points[i];
+            for point2 in &points[i + 1..] {
+                let area = (point1.x.abs_diff(point2.x) + 1) * (point1.y.abs_diff(point2.y) + 1);
+                if area > largest_area {
+                    largest_area = area;
+                }
+            }
+        }
+        largest_area
+    }
+
+    fn part2(points: &Self::Input) -> u64 {
+        let polygon = Polygon {
+            points: points.clone(),
+        };
+        polygon.largest_area()
+    }
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
-struct Point {
+pub struct Point {
     x: i64,
     y: i64,
 }
@@ -90,21 +131,14 @@ impl Polygon {
 
     fn is_point_on_edge(&self, point: &Point, edge_start: &Point, edge_end: &Point) -> bool {
         if edge_start.x == edge_end.x {
-            if point.x == edge_start.x
-                && point.y >= edge_start.y.min(edge_end.y)
-                && point.y <= edge_start.y.max(edge_end.y)
-            {
-                return true;
-            }
+            point.x == edge_start.x
+                && (edge_start.y.min(edge_end.y)..=edge_start.y.max(edge_end.y)).contains(&point.y)
         } else if edge_start.y == edge_end.y {
-            if point.y == edge_start.y
-                && point.x >= edge_start.x.min(edge_end.x)
-                && point.x <= edge_start.x.max(edge_end.x)
-            {
-                return true;
-            }
+            point.y == edge_start.y
+                && (edge_start.x.min(edge_end.x)..=edge_start.x.max(edge_end.x)).contains(&point.x)
+        } else {
+            false
         }
-        false
     }
 
     fn largest_area(&self) -> u64 {
@@ -158,72 +192,30 @@ impl Polygon {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Point> {
-    read_lines(input)
-        .expect("Failed to read input file")
-        .map_while(Result::ok)
-        .map(|line| {
-            let coords = line
-                .split(',')
-                .map(|s| s.parse().expect("Failed to parse number"))
-                .collect::<Vec<i64>>();
-            Point {
-                x: coords[0],
-                y: coords[1],
-            }
-        })
-        .collect()
-}
-
-fn solve_part1(input: &str) -> u64 {
-    let points = parse_input(input);
-    let mut largest_area = 0;
-    for i in 0..points.len() - 1 {
-        let point1 = &points[i];
-        for j in i + 1..points.len() {
-            let point2 = &points[j];
-            let area = (point1.x.abs_diff(point2.x) + 1) * (point1.y.abs_diff(point2.y) + 1);
-            if area > largest_area {
-                largest_area = area;
-            }
-        }
-    }
-    let result = largest_area;
-    println!("Part 1: {}", result);
-    result
-}
-
-fn solve_part2(input: &str) -> u64 {
-    let points = parse_input(input);
-    let polygon = Polygon { points };
-
-    let result = polygon.largest_area();
-    println!("Part 2: {}", result);
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use adventofcode25::example_path;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
 
     #[test]
-    fn part1_example() {
-        assert_eq!(solve_part1(&example_path(DAY)), 50);
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day09>(example_path(DAY))?, 50);
+        Ok(())
     }
 
     #[test]
-    fn part1_real() {
-        solve_part1(&input_path(DAY));
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day09>()
     }
 
     #[test]
-    fn part2_example() {
-        assert_eq!(solve_part2(&example_path(DAY)), 24);
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day09>(example_path(DAY))?, 24);
+        Ok(())
     }
 
     #[test]
-    fn part2_real() {
-        solve_part2(&input_path(DAY));
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day09>()
     }
 }
@@ -0,0 +1,229 @@
+use adventofcode25::{AocError, Result, Solution, column_of, parse_token};
+use pathfinding::prelude::astar;
+use z3::{Optimize, SatResult};
+
+const DAY: u8 = 10;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Machine>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_machine(line, index + 1))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> u64 {
+        fewest_presses_for_lights(machines)
+    }
+
+    fn part2(machines: &Self::Input) -> u64 {
+        fewest_presses_for_joltage(machines)
+    }
+}
+
+pub struct Machine {
+    required_lights: LightState,
+    buttons: Vec<Vec<u16>>,
+    joltage_requirements: JoltageRequirements,
+}
+
+type LightState = Vec<bool>;
+type JoltageRequirements = Vec<u16>;
+
+fn parse_machine(line: &str, line_number: usize) -> Result<Machine> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 2 {
+        return Err(AocError::parse(
+            line_number,
+            1,
+            "expected a light diagram, buttons and joltage requirements",
+        ));
+    }
+
+    let light_config = bracketed(line, line_number, parts[0], '[', ']')?;
+    let required_lights = light_config
+        .chars()
+        .map(|c| c == '#')
+        .collect::<LightState>();
+
+    let joltage_requirements = bracketed(line, line_number, parts[parts.len() - 1], '{', '}')?
+        .split(',')
+        .map(|s| parse_token(line, line_number, s))
+        .collect::<Result<JoltageRequirements>>()?;
+
+    let buttons = parts[1..parts.len() - 1]
+        .iter()
+        .map(|button_part| {
+            bracketed(line, line_number, button_part, '(', ')')?
+                .split(',')
+                .map(|s| {
+                    let light: u16 = parse_token(line, line_number, s)?;
+                    if light as usize >= required_lights.len() {
+                        return Err(AocError::parse(
+                            line_number,
+                            column_of(line, s),
+                            format!(
+                                "button wires light {light}, but there are only {}",
+                                required_lights.len()
+                            ),
+                        ));
+                    }
+                    Ok(light)
+                })
+                .collect::<Result<Vec<u16>>>()
+        })
+        .collect::<Result<Vec<Vec<u16>>>>()?;
+
+    Ok(Machine {
+        required_lights,
+        buttons,
+        joltage_requirements,
+    })
+}
+
+/// Returns the contents of `part` between `open` and `close`.
+fn bracketed<'a>(
+    line: &str,
+    line_number: usize,
+    part: &'a str,
+    open: char,
+    close: char,
+) -> Result<&'a str> {
+    part.strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+        .ok_or_else(|| {
+            AocError::parse(
+                line_number,
+                column_of(line, part),
+                format!("expected {open}...{close}, found {part:?}"),
+            )
+        })
+}
+
+fn create_successors_part1(
+    buttons: &[Vec<u16>],
+) -> impl Fn(&LightState) -> Vec<(LightState, u64)> + '_ {
+    move |state: &LightState| {
+        let mut successors = Vec::new();
+        for button in buttons {
+            let mut new_state = state.clone();
+            for &light_index in button {
+                new_state[light_index as usize] = !new_state[light_index as usize];
+            }
+            successors.push((new_state, 1));
+        }
+        successors
+    }
+}
+
+fn create_heuristic_part1(_required_lights: &LightState) -> impl Fn(&LightState) -> u64 + '_ {
+    move |_state: &LightState| 1
+}
+
+fn fewest_presses_for_lights(machines: &[Machine]) -> u64 {
+    let mut result = 0u64;
+    for machine in machines {
+        let start = vec![false; machine.required_lights.len()];
+        let shortest_sequence = astar(
+            &start,
+            create_successors_part1(&machine.buttons),
+            create_heuristic_part1(&machine.required_lights),
+            |state| *state == machine.required_lights,
+        );
+        result += shortest_sequence.unwrap().1;
+    }
+    result
+}
+
+fn fewest_presses_for_joltage(machines: &[Machine]) -> u64 {
+    let mut result = 0u64;
+
+    for machine in machines {
+        let optimize = Optimize::new();
+        let num_buttons = machine.buttons.len();
+        let num_counters = machine.joltage_requirements.len();
+
+        let button_vars: Vec<_> = (0..num_buttons)
+            .map(|i| z3::ast::Int::new_const(format!("b{}", i)))
+            .collect();
+
+        let zero = z3::ast::Int::from_i64(0);
+        for var in &button_vars {
+            optimize.assert(&var.ge(&zero));
+        }
+
+        for counter_idx in 0..num_counters {
+            let target = machine.joltage_requirements[counter_idx] as i64;
+
+            let mut sum_terms: Vec<z3::ast::Int> = Vec::new();
+            for (btn_idx, button) in machine.buttons.iter().enumerate() {
+                if button.contains(&(counter_idx as u16)) {
+                    sum_terms.push(button_vars[btn_idx].clone());
+                }
+            }
+
+            let sum = if sum_terms.is_empty() {
+                z3::ast::Int::from_i64(0)
+            } else {
+                let refs: Vec<_> = sum_terms.iter().collect();
+                z3::ast::Int::add(&refs)
+            };
+
+            let target_val = z3::ast::Int::from_i64(target);
+            optimize.assert(&sum.eq(&target_val));
+        }
+
+        let var_refs: Vec<_> = button_vars.iter().collect();
+        let total_presses = z3::ast::Int::add(&var_refs);
+        optimize.minimize(&total_presses);
+
+        if optimize.check(&[]) == SatResult::Sat {
+            let model = optimize.get_model().unwrap();
+            let mut machine_presses = 0u64;
+            for var in &button_vars {
+                let val = model.eval(var, true).unwrap().as_i64().unwrap();
+                machine_presses += val as u64;
+            }
+            result += machine_presses;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day10>(example_path(DAY))?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day10>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day10>(example_path(DAY))?, 33);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day10>()
+    }
+}
@@ -1,47 +1,52 @@
 use std::collections::HashMap;
 
-use adventofcode25::{input_path, read_lines};
+use adventofcode25::{AocError, Result, Solution};
 
 const DAY: u8 = 11;
 
-fn main() {
-    let input = parse_input(&input_path(DAY));
-    solve_part1(&input);
-    solve_part2(&input);
-}
+pub struct Day11;
 
-struct Input {
+pub struct Input {
     vertices: HashMap<String, Vec<String>>,
 }
 
-fn parse_input(input: &str) -> Input {
-    read_lines(input)
-        .expect("Failed to read input file")
-        .map_while(Result::ok)
-        .map(|line| {
-            let parts = line.split(':').collect::<Vec<&str>>();
-            let node = parts[0].trim().to_string();
-            let edges = parts[1]
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut vertices = HashMap::new();
+        for (index, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let (node, edges) = line.split_once(':').ok_or_else(|| {
+                AocError::parse(
+                    index + 1,
+                    1,
+                    format!("expected 'node: outputs...', found {line:?}"),
+                )
+            })?;
+            let edges = edges
                 .split_whitespace()
                 .map(|s| s.trim().to_string())
                 .collect::<Vec<String>>();
-            (node, edges)
-        })
-        .fold(
-            Input {
-                vertices: HashMap::new(),
-            },
-            |mut acc, (node, edges)| {
-                acc.vertices.insert(node, edges);
-                acc
-            },
-        )
-}
+            vertices.insert(node.trim().to_string(), edges);
+        }
+        Ok(Input { vertices })
+    }
+
+    fn part1(input: &Input) -> u64 {
+        count_paths(input, vec!["you"], "out")
+    }
 
-fn solve_part1(input: &Input) -> u64 {
-    let result = count_paths(input, vec!["you"], "out");
-    println!("Part 1: {}", result);
-    result
+    fn part2(input: &Input) -> u64 {
+        let mut memo = HashMap::new();
+        count_paths_with_dac_fft(&input.vertices, "svr", "out", (false, false), &mut memo)
+    }
 }
 
 fn count_paths(input: &Input, current_path: Vec<&str>, end: &str) -> u64 {
@@ -93,39 +98,32 @@ fn count_paths_with_dac_fft(
     total
 }
 
-fn solve_part2(input: &Input) -> u64 {
-    let mut memo = HashMap::new();
-    let result = count_paths_with_dac_fft(&input.vertices, "svr", "out", (false, false), &mut memo);
-    println!("Part 2: {}", result);
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use adventofcode25::{example_path, example_path_n};
+    use adventofcode25::{
+        example_path, example_path_n, solve_part1, solve_part2, verify_part1, verify_part2,
+    };
 
     #[test]
-    fn part1_example() {
-        let input = parse_input(&example_path(DAY));
-        assert_eq!(solve_part1(&input), 5);
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day11>(example_path(DAY))?, 5);
+        Ok(())
     }
 
     #[test]
-    fn part1_real() {
-        let input = parse_input(&input_path(DAY));
-        solve_part1(&input);
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day11>()
     }
 
     #[test]
-    fn part2_example() {
-        let input = parse_input(&example_path_n(DAY, 2));
-        assert_eq!(solve_part2(&input), 2);
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day11>(example_path_n(DAY, 2))?, 2);
+        Ok(())
     }
 
     #[test]
-    fn part2_real() {
-        let input = parse_input(&input_path(DAY));
-        solve_part2(&input);
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day11>()
     }
 }
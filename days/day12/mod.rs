@@ -0,0 +1,149 @@
+use adventofcode25::parse::sections;
+use adventofcode25::{AocError, Result, Solution};
+
+const DAY: u8 = 12;
+
+pub struct Day12;
+
+type Coordinate = (u64, u64);
+
+struct Present {
+    coords: Vec<Coordinate>,
+}
+
+struct Region {
+    size: (u64, u64),
+    shape_quantities: [u64; 6],
+}
+
+pub struct Input {
+    #[allow(dead_code)]
+    presents: Vec<Present>,
+    regions: Vec<Region>,
+}
+
+fn parse_input(input: &str) -> Result<Input> {
+    let sections = sections(input);
+    let Some((regions, shapes)) = sections.split_last() else {
+        return Ok(Input {
+            presents: Vec::new(),
+            regions: Vec::new(),
+        });
+    };
+
+    let presents = shapes
+        .iter()
+        .map(|shape| {
+            // The first line is the shape's index, e.g. `0:`.
+            let coords = shape
+                .lines()
+                .skip(1)
+                .enumerate()
+                .flat_map(|(y, line)| {
+                    line.text
+                        .char_indices()
+                        .filter(|&(_, c)| c == '#')
+                        .map(move |(x, _)| (x as u64, y as u64))
+                })
+                .collect();
+            Present { coords }
+        })
+        .collect();
+
+    let regions = regions.records(|line| {
+        let (size, quantities) = line.split_pair(":")?;
+        let (width, height) = line.split_token(size, "x")?;
+        let shape_quantities = quantities
+            .split_whitespace()
+            .map(|n| line.parse(n))
+            .collect::<Result<Vec<u64>>>()?;
+        let shape_quantities: [u64; 6] = shape_quantities.try_into().map_err(|q: Vec<u64>| {
+            AocError::shape(format!(
+                "region on line {} lists {} shape quantities, expected 6",
+                line.number,
+                q.len()
+            ))
+        })?;
+        Ok(Region {
+            size: (line.parse(width)?, line.parse(height)?),
+            shape_quantities,
+        })
+    })?;
+
+    Ok(Input { presents, regions })
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = DAY;
+
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Input) -> u64 {
+        let result = 0;
+        for _region in &input.regions {}
+        result // STUB
+    }
+
+    fn part2(_input: &Input) -> u64 {
+        0 // STUB
+    }
+}
+
+#[allow(dead_code)]
+fn fast_pruning(region: &Region, presents: &[Present]) -> Option<bool> {
+    let presents_area = presents
+        .iter()
+        .enumerate()
+        .map(|(index, p)| region.shape_quantities[index] * p.coords.len() as u64)
+        .sum::<u64>();
+    if region.size.0 * region.size.1 < presents_area {
+        return Some(false);
+    }
+
+    let available_blocks = region.size.0 / 3 * region.size.1 / 3;
+    let required_blocks = presents
+        .iter()
+        .enumerate()
+        .map(|(index, _)| region.shape_quantities[index])
+        .sum::<u64>();
+
+    if required_blocks <= available_blocks {
+        return Some(true);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day12>(example_path(DAY))?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day12>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day12>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day12>()
+    }
+}
@@ -0,0 +1,221 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::{AocError, Result, column_of};
+
+/// Accepted answers for one day, stored in `days/dayNN/answers.toml`.
+///
+/// The file is a small TOML subset: `part1 = "..."` and `part2 = "..."`, an optional
+/// `solved = [1, 2]` for parts solved before their answers were recorded, blank lines and `#`
+/// comments. A missing file means nothing is recorded yet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Parts marked as solved without a recorded answer, indexed by part - 1.
+    pub solved: [bool; 2],
+}
+
+impl RecordedAnswers {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AocError::MissingInput {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        for (index, line) in contents.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let (key, value) = trimmed.split_once('=').ok_or_else(|| {
+                AocError::parse(
+                    index + 1,
+                    column_of(line, trimmed),
+                    "expected `key = \"value\"`",
+                )
+            })?;
+            let value = value.trim();
+            if key.trim() == "solved" {
+                answers.solved = parse_solved(value).ok_or_else(|| {
+                    AocError::parse(
+                        index + 1,
+                        column_of(line, value),
+                        format!("expected a list of parts like [1, 2], found {value}"),
+                    )
+                })?;
+                continue;
+            }
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .ok_or_else(|| {
+                    AocError::parse(
+                        index + 1,
+                        column_of(line, value),
+                        format!("expected a quoted answer, found {value}"),
+                    )
+                })?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                other => {
+                    return Err(AocError::parse(
+                        index + 1,
+                        column_of(line, trimmed),
+                        format!("unknown key {other:?}, expected part1, part2 or solved"),
+                    ));
+                }
+            };
+            answers.set(part, value);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether `part` has a recorded answer or is marked as solved.
+    pub fn is_solved(&self, part: u8) -> bool {
+        self.get(part).is_some() || matches!(part, 1 | 2) && self.solved[usize::from(part - 1)]
+    }
+
+    /// Records `answer` for `part`, which replaces any `solved` mark for it.
+    pub fn set(&mut self, part: u8, answer: impl Into<String>) {
+        match part {
+            1 => self.part1 = Some(answer.into()),
+            2 => self.part2 = Some(answer.into()),
+            _ => panic!("There is no part {part}"),
+        }
+        self.solved[usize::from(part - 1)] = false;
+    }
+
+    /// Compares `actual` with the recorded answer for `part`.
+    ///
+    /// Returns `Ok(false)` when nothing is recorded, so callers can report a skip
+    /// rather than a pass.
+    pub fn verify(&self, day: u8, part: u8, actual: &str) -> Result<bool> {
+        match self.get(part) {
+            None => Ok(false),
+            Some(expected) if expected == actual => Ok(true),
+            Some(expected) => Err(AocError::WrongAnswer {
+                day,
+                part,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            }),
+        }
+    }
+
+    pub fn to_toml(&self, day: u8) -> String {
+        let mut contents = format!(
+            "# Accepted answers for day {day}, recorded with `cargo run --bin aoc -- accept {day}`.\n"
+        );
+        for part in [1, 2] {
+            match self.get(part) {
+                Some(answer) => contents.push_str(&format!("part{part} = \"{answer}\"\n")),
+                None => contents.push_str(&format!("# part{part} = \"\"\n")),
+            }
+        }
+        let solved: Vec<String> = [1u8, 2]
+            .into_iter()
+            .filter(|&part| self.solved[usize::from(part - 1)])
+            .map(|part| part.to_string())
+            .collect();
+        if !solved.is_empty() {
+            contents.push_str(&format!(
+                "# Solved before answers were recorded; `accept` replaces this with the answers.\n\
+                 solved = [{}]\n",
+                solved.join(", ")
+            ));
+        }
+        contents
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P, day: u8) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_toml(day)).map_err(|source| AocError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// Parses `[1, 2]`, `[1]`, `[2]` or `[]`.
+fn parse_solved(value: &str) -> Option<[bool; 2]> {
+    let list = value.strip_prefix('[')?.strip_suffix(']')?;
+    let mut solved = [false; 2];
+    for part in list
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        match part {
+            "1" => solved[0] = true,
+            "2" => solved[1] = true,
+            _ => return None,
+        }
+    }
+    Some(solved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = RecordedAnswers::default();
+        answers.set(2, "42");
+        let parsed = RecordedAnswers::parse(&answers.to_toml(7)).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(1), None);
+        assert_eq!(parsed.get(2), Some("42"));
+    }
+
+    #[test]
+    fn solved_marks_count_until_an_answer_is_recorded() {
+        let mut answers = RecordedAnswers::parse("solved = [1, 2]\n").unwrap();
+        assert!(answers.is_solved(1) && answers.is_solved(2));
+        assert!(!answers.verify(1, 1, "3").unwrap());
+        answers.set(1, "3");
+        let toml = answers.to_toml(1);
+        assert!(toml.ends_with("part1 = \"3\"\n# part2 = \"\"\n# Solved before answers were recorded; `accept` replaces this with the answers.\nsolved = [2]\n"), "{toml}");
+        assert_eq!(RecordedAnswers::parse(&toml).unwrap(), answers);
+        assert!(!RecordedAnswers::default().is_solved(1));
+        assert!(RecordedAnswers::parse("solved = [3]\n").is_err());
+    }
+
+    #[test]
+    fn verify_distinguishes_missing_and_wrong() {
+        let answers = RecordedAnswers::parse("part1 = \"3\"\n").unwrap();
+        assert!(answers.verify(1, 1, "3").unwrap());
+        assert!(!answers.verify(1, 2, "6").unwrap());
+        assert!(matches!(
+            answers.verify(1, 1, "4"),
+            Err(AocError::WrongAnswer { .. })
+        ));
+    }
+
+    #[test]
+    fn reports_position_of_malformed_lines() {
+        match RecordedAnswers::parse("part1 = \"3\"\n  part3 = \"1\"\n") {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
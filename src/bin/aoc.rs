@@ -0,0 +1,550 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use adventofcode25::{
+    Answers, AocError, Day, InputSource, PartStatus, PuzzlePage, RecordedAnswers, Result, Stats,
+    Timings, answers_path, day_dir, example_path, example_path_n, fill_example_assertion,
+    input_path, progress_table, read_input, replace_progress, stub_parts, workspace_root,
+    write_output,
+};
+
+#[path = "../../days/mod.rs"]
+mod days;
+#[cfg(test)]
+#[path = "../rng.rs"]
+mod rng;
+
+use days::DAYS;
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+/// A named phase of a run and how to read its duration from the timings.
+type Phase = (&'static str, fn(&Timings) -> Duration);
+
+fn usage() -> ! {
+    eprintln!("Usage: cargo run --bin aoc -- run <day|all> [day...] [input]");
+    eprintln!("       cargo run --bin aoc -- bench <day|all> [day...] [--runs <n>] [input]");
+    eprintln!("       cargo run --bin aoc -- accept <day> [part] [--force]");
+    eprintln!("       cargo run --bin aoc -- import <day> <puzzle.html> [--force]");
+    eprintln!("       cargo run --bin aoc -- progress [--check]");
+    eprintln!("       cargo run --bin aoc -- tool <day> [name] [args...] [input]");
+    eprintln!("Input: --example [n]   days/dayNN/example.txt, or example_<n>.txt");
+    eprintln!("       --input <path>  any file (a single day only)");
+    eprintln!("       -               stdin (a single day only)");
+    eprintln!("Example: cargo run --bin aoc -- run 7");
+    eprintln!("         cargo run --bin aoc -- run 1-4 9");
+    eprintln!("         cargo run --bin aoc -- run all");
+    eprintln!("         cargo run --bin aoc -- run 11 --example 2");
+    eprintln!("         cat other.txt | cargo run --bin aoc -- run 7 -");
+    eprintln!("         cargo run --bin aoc -- bench all --runs 20");
+    eprintln!("         cargo run --bin aoc -- accept 7 2");
+    eprintln!("         cargo run --bin aoc -- import 7 ~/Downloads/day7.html");
+    eprintln!("         cargo run --bin aoc -- tool 1 trace --example");
+    std::process::exit(1);
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let source = match args.first().map(String::as_str) {
+        Some("run" | "bench" | "tool") => take_input_source(&mut args),
+        _ => InputSource::Real,
+    };
+
+    match args.first().map(String::as_str) {
+        Some("run") if args.len() > 1 => {
+            let selected = select_days(&args[1..]);
+            check_single_day(&selected, &source);
+            let mut failed = false;
+            for day in selected {
+                if let Err(e) = run_day(day, &source) {
+                    eprintln!("Day {:02}: {}", day.day, e);
+                    failed = true;
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Some("bench") if args.len() > 1 => {
+            let mut runs = DEFAULT_BENCH_RUNS;
+            let mut day_args = Vec::new();
+            let mut rest = args[1..].iter();
+            while let Some(arg) = rest.next() {
+                if arg == "--runs" {
+                    runs = match rest.next().and_then(|n| n.parse().ok()) {
+                        Some(n) if n > 0 => n,
+                        _ => usage(),
+                    };
+                } else {
+                    day_args.push(arg.clone());
+                }
+            }
+            if day_args.is_empty() {
+                usage();
+            }
+            let selected = select_days(&day_args);
+            check_single_day(&selected, &source);
+            if !bench_days(&selected, runs, &source) {
+                std::process::exit(1);
+            }
+        }
+        Some("accept") if args.len() > 1 => {
+            let force = args.iter().any(|a| a == "--force");
+            let positional: Vec<&String> = args[1..].iter().filter(|a| *a != "--force").collect();
+            let day = match positional.first().and_then(|d| d.parse::<u8>().ok()) {
+                Some(n) => select_days(&[n.to_string()])[0],
+                None => usage(),
+            };
+            let parts: Vec<u8> = match positional.get(1).map(|p| p.as_str()) {
+                None => vec![1, 2],
+                Some("1") => vec![1],
+                Some("2") => vec![2],
+                Some(_) => usage(),
+            };
+            if let Err(e) = accept_day(day, &parts, force) {
+                eprintln!("Day {:02}: {}", day.day, e);
+                if matches!(e, AocError::WrongAnswer { .. }) {
+                    eprintln!("Use --force to replace the recorded answer");
+                }
+                std::process::exit(1);
+            }
+        }
+        Some("import") if args.len() > 2 => {
+            let force = args.iter().any(|a| a == "--force");
+            let positional: Vec<&String> = args[1..].iter().filter(|a| *a != "--force").collect();
+            let (Some(day), [_, page]) = (
+                positional[0]
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|d| (1..=25).contains(d)),
+                &positional[..],
+            ) else {
+                usage();
+            };
+            if let Err(e) = import_page(day, Path::new(page.as_str()), force) {
+                eprintln!("Day {day:02}: {e}");
+                std::process::exit(1);
+            }
+        }
+        Some("tool") if args.len() > 1 => {
+            let day = select_days(&args[1..2]);
+            let [day] = day[..] else {
+                usage();
+            };
+            if let Err(e) = run_tool(day, &args[2..], &source) {
+                eprintln!("Day {:02}: {}", day.day, e);
+                std::process::exit(1);
+            }
+        }
+        Some("progress") => {
+            let check = match &args[1..] {
+                [] => false,
+                [flag] if flag == "--check" => true,
+                _ => usage(),
+            };
+            match update_progress(check) {
+                Ok(true) => {}
+                Ok(false) => {
+                    eprintln!("README.md progress table is out of date, run `aoc progress`");
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        _ => usage(),
+    }
+}
+
+/// Removes the input options (`--example [n]`, `--input <path>` or `-`) from `args`.
+fn take_input_source(args: &mut Vec<String>) -> InputSource {
+    let mut source = None;
+    let mut index = 0;
+    while index < args.len() {
+        let (found, taken) = match args[index].as_str() {
+            "-" => (InputSource::Stdin, 1),
+            "--input" => match args.get(index + 1) {
+                Some(path) => (InputSource::File(path.into()), 2),
+                None => usage(),
+            },
+            "--example" => match args.get(index + 1).and_then(|n| n.parse().ok()) {
+                Some(n) => (InputSource::Example(Some(n)), 2),
+                None => (InputSource::Example(None), 1),
+            },
+            _ => {
+                index += 1;
+                continue;
+            }
+        };
+        if source.replace(found).is_some() {
+            eprintln!("Only one of --example, --input and - can be given");
+            usage();
+        }
+        args.drain(index..index + taken);
+    }
+    source.unwrap_or_default()
+}
+
+/// Exits unless a file or stdin input, which belongs to one puzzle, is used with a single day.
+fn check_single_day(days: &[&Day], source: &InputSource) {
+    if matches!(source, InputSource::File(_) | InputSource::Stdin) && days.len() != 1 {
+        eprintln!("Reading {source} needs exactly one day, got {}", days.len());
+        std::process::exit(1);
+    }
+}
+
+/// Resolves day arguments (`7`, `1-4` or `all`) to the registered days, in order.
+fn select_days(args: &[String]) -> Vec<&'static Day> {
+    let mut numbers: Vec<u8> = Vec::new();
+    for arg in args {
+        if arg == "all" {
+            numbers.extend(DAYS.iter().map(|d| d.day));
+            continue;
+        }
+        let range: Option<(u8, u8)> = match arg.split_once('-') {
+            Some((start, end)) => start.parse().ok().zip(end.parse().ok()),
+            None => arg.parse().ok().map(|d| (d, d)),
+        };
+        let Some((start, end)) = range.filter(|(start, end)| start <= end) else {
+            eprintln!("Invalid day: {arg}");
+            usage();
+        };
+        numbers.extend(start..=end);
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    numbers
+        .into_iter()
+        .map(|n| match DAYS.iter().find(|d| d.day == n) {
+            Some(day) => day,
+            None => {
+                eprintln!("Day {n} is not implemented");
+                std::process::exit(1);
+            }
+        })
+        .collect()
+}
+
+fn solve_day(day: &Day, source: &InputSource) -> Result<Answers> {
+    let input = source.read(day.day)?;
+    (day.run)(&input)
+}
+
+/// Solves `day` on `source` and prints the answers, checking them against the
+/// recorded ones when the real input is used.
+fn run_day(day: &Day, source: &InputSource) -> Result<()> {
+    let recorded = RecordedAnswers::load(answers_path(day.day))?;
+    let answers = solve_day(day, source)?;
+    let timings = &answers.timings;
+
+    let on = if source.is_real() {
+        String::new()
+    } else {
+        format!(" on {source}")
+    };
+    println!(
+        "Day {:02}{} ({:.2?}, parse {:.2?})",
+        day.day,
+        on,
+        timings.total(),
+        timings.parse
+    );
+    let mut wrong = None;
+    for (part, answer, elapsed) in [
+        (1, answers.part1, timings.part1),
+        (2, answers.part2, timings.part2),
+    ] {
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("  Part {part}: failed: {e} ({elapsed:.2?})");
+                wrong.get_or_insert(e);
+                continue;
+            }
+        };
+        if !source.is_real() {
+            println!("  Part {part}: {answer} ({elapsed:.2?})");
+            continue;
+        }
+        let status = match recorded.verify(day.day, part, &answer) {
+            Ok(true) => "verified".to_string(),
+            Ok(false) => "unrecorded".to_string(),
+            Err(e) => {
+                let status = format!("WRONG, expected {}", recorded.get(part).unwrap_or_default());
+                wrong.get_or_insert(e);
+                status
+            }
+        };
+        println!("  Part {part}: {answer} [{status}] ({elapsed:.2?})");
+    }
+    wrong.map_or(Ok(()), Err)
+}
+
+/// Solves `day` and records the answers of `parts` as accepted.
+///
+/// A different, already recorded answer is only replaced with `force`. A part that fails
+/// leaves its recorded answer alone, while the other part is still recorded.
+fn accept_day(day: &Day, parts: &[u8], force: bool) -> Result<()> {
+    let path = answers_path(day.day);
+    let mut recorded = RecordedAnswers::load(&path)?;
+    let answers = solve_day(day, &InputSource::Real)?;
+
+    let mut failed = None;
+    for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+        if !parts.contains(&part) {
+            continue;
+        }
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                failed.get_or_insert(e);
+                continue;
+            }
+        };
+        if let Err(AocError::WrongAnswer { expected, .. }) = recorded.verify(day.day, part, &answer)
+            && !force
+        {
+            return Err(AocError::WrongAnswer {
+                day: day.day,
+                part,
+                expected,
+                actual: answer.clone(),
+            });
+        }
+        println!("Recorded day {:02} part {}: {}", day.day, part, answer);
+        recorded.set(part, answer);
+    }
+    recorded.save(&path, day.day)?;
+    failed.map_or(Ok(()), Err)
+}
+
+/// Runs the tool named by the first of `args`, or lists the day's tools without a name.
+fn run_tool(day: &Day, args: &[String], source: &InputSource) -> Result<()> {
+    let tool = args
+        .first()
+        .and_then(|name| day.tools.iter().find(|tool| tool.name == name));
+    match tool {
+        Some(tool) => (tool.run)(source, &args[1..]),
+        None => {
+            if let Some(name) = args.first() {
+                eprintln!("Day {:02} has no tool {name:?}", day.day);
+            }
+            if day.tools.is_empty() {
+                eprintln!("Day {:02} has no tools", day.day);
+            }
+            for tool in day.tools {
+                eprintln!("  {} {}", tool.name, tool.usage);
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Works out the status of both parts of `day` from its recorded answers, its source and,
+/// when the real input is present, what the solver currently answers.
+fn day_progress(day: &Day) -> Result<[PartStatus; 2]> {
+    let recorded = RecordedAnswers::load(answers_path(day.day))?;
+    let stubs = stub_parts(&read_input(day_dir(day.day).join("mod.rs"))?);
+    let has_input = input_path(day.day)
+        .metadata()
+        .is_ok_and(|metadata| metadata.len() > 0);
+    let answers = if has_input {
+        Some(solve_day(day, &InputSource::Real)?)
+    } else {
+        None
+    };
+
+    let mut statuses = [PartStatus::Stub; 2];
+    for (index, part) in [1, 2].into_iter().enumerate() {
+        let answer = answers.as_ref().map(|answers| {
+            if part == 1 {
+                &answers.part1
+            } else {
+                &answers.part2
+            }
+        });
+        statuses[index] = if stubs[index] {
+            PartStatus::Stub
+        } else if let Some(Err(e)) = answer {
+            println!("Day {:02} part {part}: failed: {e}", day.day);
+            PartStatus::InProgress
+        } else {
+            let answer = answer.and_then(|answer| answer.as_ref().ok());
+            match (recorded.get(part), answer) {
+                (Some(expected), Some(actual)) if expected != actual => {
+                    println!(
+                        "Day {:02} part {part}: answers {actual}, but {expected} is recorded",
+                        day.day
+                    );
+                    PartStatus::InProgress
+                }
+                (Some(_), _) => PartStatus::Solved,
+                (None, _) if recorded.is_solved(part) => PartStatus::Solved,
+                (None, _) => PartStatus::InProgress,
+            }
+        };
+    }
+    Ok(statuses)
+}
+
+/// Regenerates the progress table in README.md, or with `check` only compares it.
+///
+/// Returns whether the README was already up to date.
+fn update_progress(check: bool) -> Result<bool> {
+    let mut days = Vec::new();
+    for day in DAYS {
+        let statuses = day_progress(day)?;
+        println!(
+            "Day {:02}: {}",
+            day.day,
+            statuses.map(PartStatus::symbol).concat()
+        );
+        days.push((day.day, statuses));
+    }
+
+    let path = workspace_root().join("README.md");
+    let readme = read_input(&path)?;
+    let updated = replace_progress(&readme, &progress_table(&days))?;
+    if updated == readme {
+        println!("README.md is up to date");
+        return Ok(true);
+    }
+    if !check {
+        write_output(&path, &updated)?;
+        println!("Updated the progress table in README.md");
+    }
+    Ok(!check)
+}
+
+/// Writes the examples of a saved puzzle page to the day's directory and fills in the
+/// expected answers of its example tests.
+///
+/// Existing example files with different contents are only replaced with `force`.
+fn import_page(day: u8, page: &Path, force: bool) -> Result<()> {
+    let module_path = day_dir(day).join("mod.rs");
+    let mut source = read_input(&module_path)?;
+    let page = PuzzlePage::parse(&read_input(page)?);
+    if page.examples.is_empty() {
+        return Err(AocError::shape("no <pre><code> example blocks on the page"));
+    }
+    if let Some(title) = &page.title {
+        println!("{title}");
+    }
+
+    let example_number = |index: usize| (index > 0).then(|| index as u8 + 1);
+    for (index, example) in page.examples.iter().enumerate() {
+        let path = match example_number(index) {
+            Some(n) => example_path_n(day, n),
+            None => example_path(day),
+        };
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if existing == *example {
+            println!("  unchanged {}", path.display());
+            continue;
+        }
+        if !existing.trim().is_empty() && !force {
+            println!(
+                "  kept      {} (differs, use --force to replace)",
+                path.display()
+            );
+            continue;
+        }
+        write_output(&path, example)?;
+        println!("  wrote     {}", path.display());
+    }
+
+    for (part, example) in (1..=2).zip(&page.parts) {
+        let Some(answer) = &example.answer else {
+            println!("  part {part}: no highlighted answer found");
+            continue;
+        };
+        if answer.parse::<i128>().is_err() {
+            println!("  part {part}: answer {answer:?} is not a number, fill it in by hand");
+            continue;
+        }
+        match fill_example_assertion(&source, part, example_number(example.example), answer) {
+            Some(filled) => {
+                source = filled;
+                println!("  part {part}: expecting {answer}");
+            }
+            None => println!("  part {part}: example assertion already filled in, kept"),
+        }
+    }
+    write_output(&module_path, &source)?;
+
+    // Filled-in imports can outgrow a line; leave the layout to rustfmt when it is installed.
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(&module_path)
+        .status()
+        .is_ok_and(|status| status.success());
+    if !formatted {
+        println!("  rustfmt failed, run `cargo fmt` before committing");
+    }
+    Ok(())
+}
+
+/// Runs each day `runs` times on `source` and prints min/median/mean per phase.
+///
+/// A day that cannot be read or parsed is reported and skipped; returns whether every day ran.
+fn bench_days(days: &[&Day], runs: usize, source: &InputSource) -> bool {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean"
+    );
+    let mut total_median = Duration::ZERO;
+    let mut benched = 0;
+    for day in days {
+        let samples = source.read(day.day).and_then(|input| {
+            (0..runs)
+                .map(|_| (day.run)(&input).map(|answers| answers.timings))
+                .collect::<Result<Vec<Timings>>>()
+        });
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.day, e);
+                continue;
+            }
+        };
+        benched += 1;
+
+        let phases: [Phase; 4] = [
+            ("parse", |t| t.parse),
+            ("part 1", |t| t.part1),
+            ("part 2", |t| t.part2),
+            ("total", Timings::total),
+        ];
+        for (index, (name, phase)) in phases.iter().enumerate() {
+            let durations: Vec<Duration> = samples.iter().map(phase).collect();
+            let stats = Stats::from_samples(&durations).unwrap();
+            let label = if index == 0 {
+                format!("{:02}", day.day)
+            } else {
+                String::new()
+            };
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+                label,
+                name,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean)
+            );
+            if *name == "total" {
+                total_median += stats.median;
+            }
+        }
+    }
+    println!(
+        "{} day(s), {} run(s) each, sum of median totals: {:.2?}",
+        benched, runs, total_median
+    );
+    benched == days.len()
+}
@@ -1,120 +1,505 @@
 use std::env;
-use std::fs::{self, OpenOptions};
-use std::io::Write;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use adventofcode25::parse::lines;
+use adventofcode25::{
+    AocError, RecordedAnswers, Result, answers_path, day_dir, example_path, input_path, read_input,
+    workspace_root,
+};
+
+const REGISTRY_PATH: &str = "days/mod.rs";
+const MANIFEST_PATH: &str = "Cargo.toml";
+const TEMPLATES_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "line";
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: cargo run --bin new_day -- <day_number> [--template <name>] [--title <title>] [--force] [--dry-run]"
+    );
+    eprintln!(
+        "Example: cargo run --bin new_day -- 5 --template grid --title \"Printing Department\""
+    );
+    eprintln!(
+        "  --template  one of {} (default {DEFAULT_TEMPLATE})",
+        template_names().join(", ")
+    );
+    eprintln!("  --title    the puzzle title, used in the module doc comment");
+    eprintln!("  --force    overwrite an existing days/dayNN/mod.rs with the template");
+    eprintln!("  --dry-run  only print what would be created or changed");
+    eprintln!("Running it again for an existing day repairs missing files and registration.");
+    std::process::exit(1);
+}
+
+/// Command-line options of `new_day`.
+struct Options {
+    day: u8,
+    template: String,
+    title: Option<String>,
+    force: bool,
+    dry_run: bool,
+}
+
+fn parse_args(args: &[String]) -> Options {
+    let mut day = None;
+    let mut template = DEFAULT_TEMPLATE.to_string();
+    let mut title = None;
+    let mut force = false;
+    let mut dry_run = false;
+
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--template" => template = rest.next().cloned().unwrap_or_else(|| usage()),
+            "--title" => title = Some(rest.next().cloned().unwrap_or_else(|| usage())),
+            "--force" => force = true,
+            "--dry-run" => dry_run = true,
+            _ if day.is_none() && !arg.starts_with("--") => {
+                day = match arg.parse() {
+                    Ok(d) if (1..=25).contains(&d) => Some(d),
+                    _ => {
+                        eprintln!("Day must be a number between 1 and 25");
+                        std::process::exit(1);
+                    }
+                };
+            }
+            _ => usage(),
+        }
+    }
+
+    Options {
+        day: day.unwrap_or_else(|| usage()),
+        template,
+        title,
+        force,
+        dry_run,
+    }
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = parse_args(&args);
 
-    if args.len() != 2 {
-        eprintln!("Usage: cargo run --bin new_day -- <day_number>");
-        eprintln!("Example: cargo run --bin new_day -- 5");
+    if let Err(e) = scaffold(&options) {
+        eprintln!("{e}");
         std::process::exit(1);
     }
+}
 
-    let day: u8 = match args[1].parse() {
-        Ok(d) if d >= 1 && d <= 25 => d,
-        _ => {
-            eprintln!("Day must be a number between 1 and 25");
-            std::process::exit(1);
+/// A file the scaffold wants to exist, and what to do about it.
+struct Change {
+    path: PathBuf,
+    contents: String,
+    action: Action,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    Overwrite,
+    Update,
+    Keep,
+}
+
+impl Action {
+    fn describe(self, dry_run: bool) -> &'static str {
+        match (self, dry_run) {
+            (Action::Create, false) => "created",
+            (Action::Create, true) => "would create",
+            (Action::Overwrite, false) => "overwrote",
+            (Action::Overwrite, true) => "would overwrite",
+            (Action::Update, false) => "updated",
+            (Action::Update, true) => "would update",
+            (Action::Keep, _) => "kept",
         }
+    }
+}
+
+/// Creates whatever is missing for the day and registers it, leaving existing files alone.
+///
+/// Files holding puzzle data (inputs and recorded answers) are never overwritten;
+/// `--force` only replaces the solution module with a fresh template.
+fn scaffold(options: &Options) -> Result<()> {
+    let Options {
+        day,
+        force,
+        dry_run,
+        ..
+    } = *options;
+    let root = workspace_root();
+    let mut changes = Vec::new();
+
+    let title = match &options.title {
+        Some(title) => format!("Day {day}: {title}"),
+        None => format!("Day {day}"),
     };
+    let contents = template(&options.template, day, &title)?;
 
-    let day_dir = format!("days/day{:02}", day);
-    let main_path = format!("{}/main.rs", day_dir);
-    let input_path = format!("{}/input.txt", day_dir);
-    let example_path = format!("{}/example.txt", day_dir);
+    let main_path = day_dir(day).join("mod.rs");
+    let main_action = match (main_path.exists(), force) {
+        (false, _) => Action::Create,
+        (true, true) => Action::Overwrite,
+        (true, false) => Action::Keep,
+    };
+    changes.push(Change {
+        path: main_path,
+        contents,
+        action: main_action,
+    });
+    for (path, contents) in [
+        (input_path(day), String::new()),
+        (example_path(day), String::new()),
+        (answers_path(day), RecordedAnswers::default().to_toml(day)),
+    ] {
+        let action = if path.exists() {
+            Action::Keep
+        } else {
+            Action::Create
+        };
+        changes.push(Change {
+            path,
+            contents,
+            action,
+        });
+    }
 
-    if Path::new(&day_dir).exists() {
-        eprintln!("Day {} already exists at {}", day, day_dir);
-        std::process::exit(1);
+    let registry_path = root.join(REGISTRY_PATH);
+    let current = read_input(&registry_path)?;
+    let mut registry = Registry::parse(&current)?;
+    for problem in registry.problems() {
+        println!("{}: {}", REGISTRY_PATH, problem);
     }
+    registry.insert(day);
+    changes.push(rewrite(registry_path, &current, registry.render()));
 
-    fs::create_dir_all(&day_dir).expect("Failed to create day directory");
+    let manifest_path = root.join(MANIFEST_PATH);
+    let current = read_input(&manifest_path)?;
+    let (manifest, stale) = remove_day_bins(&current);
+    for name in &stale {
+        println!("{}: removing stale [[bin]] entry {name:?}", MANIFEST_PATH);
+    }
+    changes.push(rewrite(manifest_path, &current, manifest));
 
-    let template = format!(
-        r#"use adventofcode25::{{input_path, read_lines}};
+    for change in &changes {
+        if !dry_run && change.action != Action::Keep {
+            write(&change.path, &change.contents)?;
+        }
+        let path = change.path.strip_prefix(root).unwrap_or(&change.path);
+        println!(
+            "  {:<15} {}",
+            change.action.describe(dry_run),
+            path.display()
+        );
+    }
+    if dry_run {
+        println!("Dry run, nothing was written");
+    } else {
+        println!("Day {day} is ready at {}", day_dir(day).display());
+    }
+    Ok(())
+}
 
-const DAY: u8 = {};
+fn rewrite(path: PathBuf, current: &str, contents: String) -> Change {
+    let action = if contents == current {
+        Action::Keep
+    } else {
+        Action::Update
+    };
+    Change {
+        path,
+        contents,
+        action,
+    }
+}
 
-fn main() {{
-    solve_part1(&input_path(DAY));
-    solve_part2(&input_path(DAY));
-}}
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let to_write_error = |source| AocError::Write {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(to_write_error)?;
+    }
+    fs::write(path, contents).map_err(to_write_error)
+}
 
-fn solve_part1(input: &str) -> u64 {{
-    let lines = read_lines(input).expect("Failed to read input file");
-    for line in lines.map_while(Result::ok) {{
-        println!("{{line}}");
-    }}
-    let result = 0;
-    println!("Part 1: {{}}", result);
-    result
-}}
+/// The days declared in `days/mod.rs`, as `pub mod dayNN;` lines and entries of `DAYS`.
+///
+/// The file is always rewritten from this model, so its layout stays sorted and free of
+/// duplicates however it was edited before.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Registry {
+    modules: Vec<u8>,
+    entries: Vec<u8>,
+}
 
-fn solve_part2(input: &str) -> u64 {{
-    let lines = read_lines(input).expect("Failed to read input file");
-    for _line in lines.map_while(Result::ok) {{
-    }}
-    let result = 0;
-    println!("Part 2: {{}}", result);
-    result
-}}
+impl Registry {
+    fn parse(contents: &str) -> Result<Self> {
+        let mut registry = Registry::default();
+        let mut in_days = false;
+        for line in lines(contents) {
+            let text = line.text.trim();
+            if text.is_empty() || text == "use adventofcode25::Day;" {
+                continue;
+            }
+            if !in_days && text == "pub const DAYS: &[Day] = &[" {
+                in_days = true;
+                continue;
+            }
+            if in_days && text == "];" {
+                in_days = false;
+                continue;
+            }
+            let day = if in_days {
+                text.strip_prefix("Day::of::<")
+                    .and_then(|t| t.strip_suffix(">(),"))
+                    .and_then(|path| path.split_once("::"))
+                    .and_then(|(module, name)| {
+                        day_number(module).filter(|&day| name == format!("Day{day:02}"))
+                    })
+                    .map(|day| registry.entries.push(day))
+            } else {
+                text.strip_prefix("pub mod ")
+                    .and_then(|t| t.strip_suffix(';'))
+                    .and_then(day_number)
+                    .map(|day| registry.modules.push(day))
+            };
+            if day.is_none() {
+                let expected = if in_days {
+                    "`Day::of::<dayNN::DayNN>(),`"
+                } else {
+                    "`pub mod dayNN;`"
+                };
+                return Err(line.error(
+                    text,
+                    format!("expected {expected}, found {text:?}; fix the line by hand"),
+                ));
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Inconsistencies that rendering will repair: duplicates and half-registered days.
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (what, days) in [("module", &self.modules), ("DAYS entry", &self.entries)] {
+            let mut seen = Vec::new();
+            for &day in days {
+                if seen.contains(&day) {
+                    problems.push(format!("duplicate {what} for day{day:02}"));
+                }
+                seen.push(day);
+            }
+        }
+        for &day in &self.modules {
+            if !self.entries.contains(&day) {
+                problems.push(format!("day{day:02} is declared but missing from DAYS"));
+            }
+        }
+        for &day in &self.entries {
+            if !self.modules.contains(&day) {
+                problems.push(format!("day{day:02} is in DAYS but not declared"));
+            }
+        }
+        problems.dedup();
+        problems
+    }
+
+    fn insert(&mut self, day: u8) {
+        self.modules.push(day);
+        self.entries.push(day);
+    }
+
+    fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.modules.iter().chain(&self.entries).copied().collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    fn render(&self) -> String {
+        let days = self.days();
+        let mut contents = String::from("use adventofcode25::Day;\n\n");
+        for day in &days {
+            contents.push_str(&format!("pub mod day{day:02};\n"));
+        }
+        contents.push_str("\npub const DAYS: &[Day] = &[\n");
+        for day in &days {
+            contents.push_str(&format!("    Day::of::<day{day:02}::Day{day:02}>(),\n"));
+        }
+        contents.push_str("];\n");
+        contents
+    }
+}
+
+/// Parses a `dayNN` module name.
+fn day_number(module: &str) -> Option<u8> {
+    let digits = module.strip_prefix("day")?;
+    if digits.len() != 2 {
+        return None;
+    }
+    digits.parse().ok().filter(|day| (1..=25).contains(day))
+}
+
+/// Drops `[[bin]]` tables named `dayNN` from the manifest, returning it and the removed names.
+///
+/// Days are modules of the `aoc` runner, so such entries are leftovers of the old layout
+/// and point at files that no longer exist.
+fn remove_day_bins(manifest: &str) -> (String, Vec<String>) {
+    let mut tables: Vec<Vec<&str>> = vec![Vec::new()];
+    for line in manifest.lines() {
+        if line.trim_start().starts_with('[') {
+            tables.push(Vec::new());
+        }
+        tables.last_mut().unwrap().push(line);
+    }
+
+    let mut removed = Vec::new();
+    let mut kept = Vec::new();
+    for table in tables {
+        let name = table.iter().find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == "name").then(|| value.trim().trim_matches('"'))
+        });
+        match name {
+            Some(name)
+                if table.first().map(|l| l.trim()) == Some("[[bin]]")
+                    && day_number(name).is_some() =>
+            {
+                removed.push(name.to_string());
+            }
+            _ => kept.push(table),
+        }
+    }
+    if removed.is_empty() {
+        return (manifest.to_string(), removed);
+    }
+
+    let mut contents = String::new();
+    for table in kept {
+        let mut table = table;
+        while table.last().is_some_and(|line| line.trim().is_empty()) {
+            table.pop();
+        }
+        if table.is_empty() {
+            continue;
+        }
+        if !contents.is_empty() {
+            contents.push('\n');
+        }
+        for line in table {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+    }
+    (contents, removed)
+}
+
+/// Loads `templates/<name>.rs` and fills in its placeholders.
+///
+/// Templates are plain Rust files using `{{day}}` (e.g. `7`), `{{day_padded}}` (`07`)
+/// and `{{title}}`, so they can be edited without escaping any braces.
+fn template(name: &str, day: u8, title: &str) -> Result<String> {
+    let path = workspace_root()
+        .join(TEMPLATES_DIR)
+        .join(format!("{name}.rs"));
+    if !path.is_file() {
+        return Err(AocError::shape(format!(
+            "unknown template {name:?}, available: {}",
+            template_names().join(", ")
+        )));
+    }
+    Ok(read_input(&path)?
+        .replace("{{day}}", &day.to_string())
+        .replace("{{day_padded}}", &format!("{day:02}"))
+        .replace("{{title}}", title))
+}
+
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(workspace_root().join(TEMPLATES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+            (path.extension()? == "rs").then_some(name)
+        })
+        .collect();
+    names.sort();
+    names
+}
 
 #[cfg(test)]
-mod tests {{
+mod tests {
     use super::*;
-    use adventofcode25::example_path;
-
-    #[test]
-    fn part1_example() {{
-        assert_eq!(solve_part1(&example_path(DAY)), 0); // TODO: fill in expected value
-    }}
+    use adventofcode25::stub_parts;
 
     #[test]
-    fn part1_real() {{
-        solve_part1(&input_path(DAY));
-    }}
+    fn registry_insert_is_sorted_and_idempotent() {
+        let contents = "use adventofcode25::Day;\n\npub mod day01;\npub mod day03;\n\n\
+                        pub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    \
+                        Day::of::<day03::Day03>(),\n];\n";
+        let mut registry = Registry::parse(contents).unwrap();
+        assert_eq!(registry.render(), contents);
 
-    #[test]
-    fn part2_example() {{
-        assert_eq!(solve_part2(&example_path(DAY)), 0); // TODO: fill in expected value
-    }}
+        registry.insert(2);
+        let once = registry.render();
+        let mut again = Registry::parse(&once).unwrap();
+        again.insert(2);
+        assert_eq!(again.render(), once);
+        assert_eq!(again.days(), [1, 2, 3]);
+        assert!(once.contains("pub mod day02;\npub mod day03;"));
+    }
 
     #[test]
-    fn part2_real() {{
-        solve_part2(&input_path(DAY));
-    }}
-}}
-"#,
-        day
-    );
+    fn registry_reports_and_repairs_partial_entries() {
+        let contents = "pub mod day01;\npub mod day01;\npub mod day02;\n\
+                        pub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n];\n";
+        let registry = Registry::parse(contents).unwrap();
+        assert_eq!(
+            registry.problems(),
+            [
+                "duplicate module for day01",
+                "day02 is declared but missing from DAYS"
+            ]
+        );
+        assert!(registry.render().contains("Day::of::<day02::Day02>(),"));
 
-    fs::write(&main_path, template).expect("Failed to write main.rs");
-    fs::write(&input_path, "").expect("Failed to write input.txt");
-    fs::write(&example_path, "").expect("Failed to write example.txt");
-
-    let cargo_entry = format!(
-        r#"
-[[bin]]
-name = "day{:02}"
-path = "days/day{:02}/main.rs"
-"#,
-        day, day
-    );
-
-    let mut cargo_file = OpenOptions::new()
-        .append(true)
-        .open("Cargo.toml")
-        .expect("Failed to open Cargo.toml");
+        assert!(matches!(
+            Registry::parse("pub mod utils;\n"),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+    }
 
-    cargo_file
-        .write_all(cargo_entry.as_bytes())
-        .expect("Failed to write to Cargo.toml");
+    #[test]
+    fn every_template_fills_in_its_placeholders() {
+        let names = template_names();
+        assert!(names.contains(&DEFAULT_TEMPLATE.to_string()));
+        for name in names {
+            let contents = template(&name, 7, "Day 7: Laboratories").unwrap();
+            assert!(!contents.contains("{{"), "{name} has unfilled placeholders");
+            assert!(contents.starts_with("//! Day 7: Laboratories\n"));
+            assert!(contents.contains("const DAY: u8 = 7;"));
+            assert!(contents.contains("impl Solution for Day07 {"));
+            assert_eq!(
+                stub_parts(&contents),
+                [true, true],
+                "{name} parts are not stubs"
+            );
+        }
+        assert!(template("missing", 7, "").is_err());
+    }
 
-    println!("Created day {} at {}", day, day_dir);
-    println!("  - {}", main_path);
-    println!("  - {}", input_path);
-    println!("  - {}", example_path);
-    println!("  - Added [[bin]] entry to Cargo.toml");
+    #[test]
+    fn removes_only_day_bins_from_the_manifest() {
+        let manifest = "[package]\nname = \"x\"\n\n[[bin]]\nname = \"aoc\"\n\n[dependencies]\n\
+                        regex = \"1\"\n\n[[bin]]\nname = \"day03\"\npath = \"days/day03/main.rs\"\n";
+        let (cleaned, removed) = remove_day_bins(manifest);
+        assert_eq!(removed, ["day03"]);
+        assert_eq!(
+            cleaned,
+            "[package]\nname = \"x\"\n\n[[bin]]\nname = \"aoc\"\n\n[dependencies]\nregex = \"1\"\n"
+        );
+        assert_eq!(remove_day_bins(&cleaned), (cleaned.clone(), Vec::new()));
+    }
 }
@@ -0,0 +1,107 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Everything that can go wrong between locating an input file and producing an answer.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file could not be read.
+    MissingInput { path: PathBuf, source: io::Error },
+    /// A token in the input could not be parsed. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed, but does not have the structure the solver expects.
+    UnsupportedShape(String),
+    /// A file other than the puzzle input could not be written.
+    Write { path: PathBuf, source: io::Error },
+    /// A solver produced an answer that differs from the recorded, accepted one.
+    WrongAnswer {
+        day: u8,
+        part: u8,
+        expected: String,
+        actual: String,
+    },
+    /// A command-line argument of a day's tool is unknown or malformed.
+    InvalidArgument(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn shape(message: impl Into<String>) -> Self {
+        AocError::UnsupportedShape(message.into())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInput { path, source } => {
+                write!(f, "cannot read input {}: {}", path.display(), source)
+            }
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            AocError::UnsupportedShape(message) => write!(f, "unsupported input: {message}"),
+            AocError::Write { path, source } => {
+                write!(f, "cannot write {}: {}", path.display(), source)
+            }
+            AocError::WrongAnswer {
+                day,
+                part,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "day {day} part {part} answered {actual}, but the recorded answer is {expected}"
+            ),
+            AocError::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::MissingInput { source, .. } | AocError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the 1-based column at which `token` starts within `line`.
+///
+/// `token` must be a subslice of `line`, as produced by `split`, `trim` and friends.
+pub fn column_of(line: &str, token: &str) -> usize {
+    token.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// Parses `token`, a subslice of the 1-based `line_number`th `line`, reporting its position on failure.
+pub fn parse_token<T>(line: &str, line_number: usize, token: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse().map_err(|e| {
+        AocError::parse(
+            line_number,
+            column_of(line, token),
+            format!("invalid value {token:?}: {e}"),
+        )
+    })
+}
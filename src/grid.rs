@@ -0,0 +1,296 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+
+use crate::{AocError, Result, read_input};
+
+/// A `(row, column)` position in a [`Grid`].
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from text, one row per line, mapping each character with `cell`.
+    ///
+    /// A character for which `cell` returns `None` is reported as a parse error at its
+    /// position, and rows of different lengths as an unsupported shape.
+    pub fn from_str(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Self::build(input, || None, cell)
+    }
+
+    /// Like [`Grid::from_str`], but short rows are padded with `fill` up to the longest row.
+    pub fn from_str_padded(
+        input: &str,
+        fill: T,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self>
+    where
+        T: Clone,
+    {
+        Self::build(input, || Some(fill.clone()), cell)
+    }
+
+    /// Reads the file at `path` and builds a grid from it with [`Grid::from_str`].
+    pub fn from_file<P: AsRef<Path>>(path: P, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Self::from_str(&read_input(path)?, cell)
+    }
+
+    fn build(
+        input: &str,
+        mut fill: impl FnMut() -> Option<T>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let lines: Vec<&str> = input.lines().collect();
+        let height = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |last| last + 1);
+        let lines = &lines[..height];
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, line) in lines.iter().enumerate() {
+            let mut len = 0;
+            for (col, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    AocError::parse(row + 1, col + 1, format!("unexpected character {c:?}"))
+                })?;
+                cells.push(value);
+                len += 1;
+            }
+            for _ in len..width {
+                let padding = fill().ok_or_else(|| {
+                    AocError::shape(format!(
+                        "row {} has {} cells, expected {}",
+                        row + 1,
+                        len,
+                        width
+                    ))
+                })?;
+                cells.push(padding);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position `delta` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+        self.in_bounds((row, col)).then_some((row, col))
+    }
+
+    /// The up to four orthogonally adjacent positions of `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// The up to eight orthogonally and diagonally adjacent positions of `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// The first position, in row-major order, whose cell equals `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The first position, in row-major order, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| (index / self.width, index % self.width))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `col`, top to bottom. Panics if `col` is outside the grid, like
+    /// [`Grid::row`] does for rows.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {col} is outside a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            self.in_bounds((row, col)),
+            "({row}, {col}) is outside the grid"
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            self.in_bounds((row, col)),
+            "({row}, {col}) is outside the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_round_trip() {
+        let grid = Grid::from_str("#.\n.#\n", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+        assert_eq!(grid.find(&'#'), Some((0, 0)));
+        assert_eq!(grid.column(1).collect::<String>(), ".#");
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0u8);
+        assert_eq!(grid.neighbors4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.get((3, 0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 is outside a grid of width 2")]
+    fn column_out_of_bounds_panics() {
+        let grid = Grid::new(2, 3, 0u8);
+        let _ = grid.column(2);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_unless_padded() {
+        assert!(matches!(
+            Grid::from_str("ab\nc\n", Some),
+            Err(AocError::UnsupportedShape(_))
+        ));
+        let padded = Grid::from_str_padded("ab\nc\n", ' ', Some).unwrap();
+        assert_eq!(padded.row(1), &['c', ' ']);
+    }
+
+    #[test]
+    fn reports_position_of_unexpected_characters() {
+        let result = Grid::from_str("..\n.x\n", |c| (c == '.').then_some(()));
+        match result {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use crate::{AocError, Grid, Result};
+
+/// A colour as red, green and blue bytes.
+pub type Rgb = [u8; 3];
+
+/// An RGB raster that can be encoded as PPM, PNG or, as a sequence of frames, an animated GIF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// Draws every cell of `grid` as a `scale` × `scale` square in the colour `color` gives it.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut color: impl FnMut(&T) -> Rgb) -> Self {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, [0; 3]);
+        for ((row, col), cell) in grid.iter() {
+            let rgb = color(cell);
+            for y in row * scale..(row + 1) * scale {
+                let start = y * image.width + col * scale;
+                image.pixels[start..start + scale].fill(rgb);
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The image as a binary (`P6`) PPM file.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+
+    /// The image as an 8-bit RGB PNG file.
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let (width, height) = (dimension(self.width)?, dimension(self.height)?);
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(png_error)?;
+        writer
+            .write_image_data(self.pixels.as_flattened())
+            .map_err(png_error)?;
+        writer.finish().map_err(png_error)?;
+        Ok(png)
+    }
+}
+
+fn dimension<T: TryFrom<usize>>(size: usize) -> Result<T> {
+    T::try_from(size).map_err(|_| AocError::shape(format!("{size} pixels is too large an image")))
+}
+
+fn png_error(error: png::EncodingError) -> AocError {
+    AocError::shape(format!("cannot encode PNG: {error}"))
+}
+
+fn gif_error(error: gif::EncodingError) -> AocError {
+    AocError::shape(format!("cannot encode GIF: {error}"))
+}
+
+/// Encodes `frames` as a looping animated GIF, showing each for `delay` hundredths of a second.
+///
+/// All frames must have the size of the first and together use at most 256 colours, which
+/// share one global palette.
+pub fn encode_gif(frames: &[Image], delay: u16) -> Result<Vec<u8>> {
+    let first = frames
+        .first()
+        .ok_or_else(|| AocError::shape("an animation needs at least one frame"))?;
+    let (width, height) = (first.width, first.height);
+    if let Some(frame) = frames
+        .iter()
+        .find(|frame| (frame.width, frame.height) != (width, height))
+    {
+        return Err(AocError::shape(format!(
+            "frame of {}x{} in an animation of {width}x{height}",
+            frame.width, frame.height
+        )));
+    }
+    let (width, height) = (dimension(width)?, dimension(height)?);
+
+    let mut palette: Vec<Rgb> = Vec::new();
+    let mut index: HashMap<Rgb, u8> = HashMap::new();
+    for &rgb in frames.iter().flat_map(|frame| &frame.pixels) {
+        if let Entry::Vacant(slot) = index.entry(rgb) {
+            if palette.len() == 256 {
+                return Err(AocError::shape("a GIF can show at most 256 colours"));
+            }
+            slot.insert(palette.len() as u8);
+            palette.push(rgb);
+        }
+    }
+
+    let mut gif = Vec::new();
+    let mut encoder =
+        gif::Encoder::new(&mut gif, width, height, palette.as_flattened()).map_err(gif_error)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(gif_error)?;
+    for frame in frames {
+        let indices: Vec<u8> = frame.pixels.iter().map(|rgb| index[rgb]).collect();
+        encoder
+            .write_frame(&gif::Frame {
+                delay,
+                width,
+                height,
+                buffer: indices.into(),
+                ..gif::Frame::default()
+            })
+            .map_err(gif_error)?;
+    }
+    encoder.into_inner().map_err(gif_error)?;
+    Ok(gif)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_ppm_png_and_gif() -> Result<()> {
+        assert_eq!(
+            Image::new(1, 2, [1, 2, 3]).to_ppm(),
+            b"P6\n1 2\n255\n\x01\x02\x03\x01\x02\x03"
+        );
+
+        let image = Image::new(2, 3, [255, 0, 0]);
+        let png = image.to_png()?;
+        let decoder = png::Decoder::new(std::io::Cursor::new(png));
+        let mut reader = decoder.read_info().expect("a valid PNG");
+        let mut pixels = vec![0; reader.output_buffer_size().expect("a small image")];
+        let info = reader.next_frame(&mut pixels).expect("one frame");
+        assert_eq!((info.width, info.height), (2, 3));
+        assert_eq!(pixels, image.pixels.as_flattened());
+
+        let frames = [image.clone(), Image::new(2, 3, [0, 0, 255])];
+        let gif = encode_gif(&frames, 10)?;
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = decoder.read_info(gif.as_slice()).expect("a valid GIF");
+        for frame in &frames {
+            let decoded = decoder
+                .read_next_frame()
+                .expect("a frame")
+                .expect("a frame");
+            let rgb: Vec<u8> = decoded
+                .buffer
+                .chunks(4)
+                .flat_map(|p| &p[..3])
+                .copied()
+                .collect();
+            assert_eq!(rgb, frame.pixels.as_flattened());
+        }
+        assert!(encode_gif(&[image, Image::new(3, 2, [0; 3])], 10).is_err());
+        Ok(())
+    }
+}
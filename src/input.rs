@@ -0,0 +1,95 @@
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::{AocError, Result, example_path, example_path_n, input_path, read_input};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `days/dayNN/input.txt`, the only input with recorded answers.
+    #[default]
+    Real,
+    /// `days/dayNN/example.txt`, or `example_N.txt` when a number is given.
+    Example(Option<u8>),
+    /// An arbitrary file, resolved against the current directory.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The file this source reads for `day`, or `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Real => Some(input_path(day)),
+            InputSource::Example(None) => Some(example_path(day)),
+            InputSource::Example(Some(n)) => Some(example_path_n(day, *n)),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self.path(day) {
+            Some(path) => read_input(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(|source| {
+                    AocError::MissingInput {
+                        path: PathBuf::from("-"),
+                        source,
+                    }
+                })?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// A buffered reader over the source, for inputs too large to read into memory at once.
+    pub fn reader(&self, day: u8) -> Result<Box<dyn BufRead>> {
+        match self.path(day) {
+            Some(path) => {
+                let file =
+                    File::open(&path).map_err(|source| AocError::MissingInput { path, source })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            None => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn is_real(&self) -> bool {
+        *self == InputSource::Real
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Real => write!(f, "input.txt"),
+            InputSource::Example(None) => write!(f, "example.txt"),
+            InputSource::Example(Some(n)) => write!(f, "example_{n}.txt"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// The directory holding `Cargo.toml` and `days/`, which all day paths are relative to.
+///
+/// Found by walking up from the current directory, so the tools work from any
+/// subdirectory of the checkout; falls back to the directory the crate was built from.
+pub fn workspace_root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        std::env::current_dir()
+            .ok()
+            .and_then(|cwd| {
+                cwd.ancestors()
+                    .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("days").is_dir())
+                    .map(Path::to_path_buf)
+            })
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+    })
+}
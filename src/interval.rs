@@ -0,0 +1,243 @@
+use std::fmt::Debug;
+
+/// An integer type usable as the endpoint of an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord + Debug {
+    /// `self + 1`, or `None` at the maximum value.
+    fn successor(self) -> Option<Self>;
+    /// `self - 1`, or `None` at the minimum value.
+    fn predecessor(self) -> Option<Self>;
+    /// The number of values in `start..=end`, which never overflows a `u128`.
+    fn width(start: Self, end: Self) -> u128;
+}
+
+macro_rules! endpoint {
+    ($($int:ty),*) => {$(
+        impl Endpoint for $int {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn width(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128 + 1) as u128
+            }
+        }
+    )*};
+}
+
+endpoint!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers stored as sorted, disjoint inclusive ranges.
+///
+/// Overlapping and adjacent ranges are merged on insertion, so `1-3` and `4-6` become `1-6`
+/// and every value is covered by at most one stored range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The merged ranges in increasing order.
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds `start..=end`, merging it with the ranges it overlaps or touches. An empty range,
+    /// with `start > end`, is ignored.
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // The stored ranges ending before `start - 1` and starting after `end + 1` are kept.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.successor().is_some_and(|after| after < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.successor().is_none_or(|after| s <= after));
+        let merged = match self.ranges[first..last] {
+            [] => (start, end),
+            [(s, _), .., (_, e)] | [(s, e)] => (start.min(s), end.max(e)),
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` lies in one of the ranges, found by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        self.range_of(value).is_some()
+    }
+
+    /// The merged range containing `value`.
+    pub fn range_of(&self, value: T) -> Option<(T, T)> {
+        let index = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(index)
+            .copied()
+            .filter(|&(start, _)| start <= value)
+    }
+
+    /// The number of values in the set.
+    pub fn total_len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::width(start, end))
+            .sum()
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).copied().collect()
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever range ends first cannot overlap anything further in the other set.
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for &(start, end) in &self.ranges {
+            // The part of `start..=end` not yet cut away, or `None` once nothing is left.
+            let mut rest = Some(start);
+            while let (Some(from), Some(&(cut_start, cut_end))) = (rest, other.ranges.get(j)) {
+                if cut_end < from {
+                    j += 1;
+                    continue;
+                }
+                if cut_start > end {
+                    break;
+                }
+                if let Some(before) = cut_start.predecessor()
+                    && from <= before
+                {
+                    ranges.push((from, before));
+                }
+                rest = cut_end.successor().filter(|&after| after <= end);
+                if cut_end > end {
+                    break;
+                }
+                j += 1;
+            }
+            if let Some(from) = rest {
+                ranges.push((from, end));
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Endpoint> FromIterator<(T, T)> for IntervalSet<T> {
+    /// Sorts the ranges first, so building from `n` ranges takes O(n log n).
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut sorted: Vec<(T, T)> = iter
+            .into_iter()
+            .filter(|&(start, end)| start <= end)
+            .collect();
+        sorted.sort_unstable();
+        let mut ranges: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match ranges.last_mut() {
+                Some((_, last_end)) if last_end.successor().is_none_or(|after| start <= after) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => ranges.push((start, end)),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let mut set: IntervalSet<u64> =
+            [(10, 14), (16, 20), (3, 5), (12, 18)].into_iter().collect();
+        assert_eq!(set.ranges(), [(3, 5), (10, 20)]);
+        set.insert(6, 9);
+        assert_eq!(set.ranges(), [(3, 20)]);
+        set.insert(u64::MAX - 1, u64::MAX);
+        set.insert(0, 0);
+        assert_eq!(set.ranges(), [(0, 0), (3, 20), (u64::MAX - 1, u64::MAX)]);
+        assert_eq!(set.total_len(), 21);
+        assert!(set.contains(u64::MAX) && set.contains(3) && !set.contains(21));
+        assert_eq!(set.range_of(7), Some((3, 20)));
+    }
+
+    #[test]
+    fn set_operations_match_a_plain_set() {
+        let mut rng = Rng::new(0xda94_2042_e4dd_58b5);
+        let mut random_set = || {
+            let mut set = IntervalSet::new();
+            let count = rng.below(8);
+            for _ in 0..count {
+                let start = rng.below(256) as u8;
+                let end = start.saturating_add(rng.below(40) as u8);
+                set.insert(start, end);
+            }
+            set
+        };
+        let values = |set: &IntervalSet<u8>| -> BTreeSet<u8> {
+            set.ranges().iter().flat_map(|&(s, e)| s..=e).collect()
+        };
+        for _ in 0..500 {
+            let (a, b) = (random_set(), random_set());
+            let (va, vb) = (values(&a), values(&b));
+            for (result, expected) in [
+                (a.union(&b), &va | &vb),
+                (a.intersection(&b), &va & &vb),
+                (a.difference(&b), &va - &vb),
+            ] {
+                assert_eq!(values(&result), expected, "{a:?} {b:?}");
+                // The result is normalised: building it again from its own ranges changes nothing.
+                assert_eq!(
+                    result.ranges().iter().copied().collect::<IntervalSet<_>>(),
+                    result
+                );
+                assert_eq!(result.total_len(), expected.len() as u128);
+            }
+            for value in 0..=u8::MAX {
+                assert_eq!(a.contains(value), va.contains(&value));
+            }
+        }
+    }
+}
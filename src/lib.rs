@@ -1,23 +1,226 @@
-use std::fs::File;
-use std::io::{self, BufRead, BufReader, Lines};
-use std::path::Path;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Lines};
+use std::path::{Path, PathBuf};
 
-pub fn read_lines<P>(filename: P) -> io::Result<Lines<BufReader<File>>>
+mod answers;
+mod error;
+mod grid;
+mod image;
+mod input;
+mod interval;
+pub mod parse;
+mod progress;
+mod puzzle;
+#[cfg(test)]
+mod rng;
+mod timing;
+mod tool;
+
+pub use answers::RecordedAnswers;
+pub use error::{AocError, Result, column_of, parse_token};
+pub use grid::{Grid, Pos};
+pub use image::{Image, Rgb, encode_gif};
+pub use input::{InputSource, workspace_root};
+pub use interval::{Endpoint, IntervalSet};
+pub use progress::{PartStatus, progress_table, replace_progress, stub_parts};
+pub use puzzle::{PartExample, PuzzlePage, fill_example_assertion};
+pub use timing::{Stats, Timings, timed};
+pub use tool::{Tool, ToolArgs};
+
+/// A single day's puzzle, split into parsing and the two parts.
+///
+/// `parse` receives the raw input text so that the runner can time it
+/// separately and reuse the parsed input for both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// Extra commands of this day, run with `aoc tool <day> <name>`.
+    const TOOLS: &'static [Tool] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// What `part1` and `part2` return: a plain answer, or a [`Result`] for parts that can fail on
+/// some inputs. The error of a failed part is reported by the runner like a parse error.
+pub trait Answer {
+    type Value: Display;
+
+    fn into_value(self) -> Result<Self::Value>;
+}
+
+macro_rules! plain_answer {
+    ($($answer:ty),*) => {$(
+        impl Answer for $answer {
+            type Value = Self;
+
+            fn into_value(self) -> Result<Self> {
+                Ok(self)
+            }
+        }
+    )*};
+}
+
+plain_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String
+);
+
+impl<T: Answer> Answer for Result<T> {
+    type Value = T::Value;
+
+    fn into_value(self) -> Result<T::Value> {
+        self?.into_value()
+    }
+}
+
+/// The answers of both parts, rendered for display, and how long each phase took.
+///
+/// Each part succeeds or fails on its own, so one failing part doesn't hide the other's answer.
+pub struct Answers {
+    pub part1: Result<String>,
+    pub part2: Result<String>,
+    pub timings: Timings,
+}
+
+/// A type-erased entry in the list of days known to the runner.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str) -> Result<Answers>,
+    pub tools: &'static [Tool],
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            run: run::<S>,
+            tools: S::TOOLS,
+        }
+    }
+}
+
+/// Parses `input` once and solves both parts of `S`, timing each phase.
+///
+/// Rendering the answers to strings is not included in the part timings.
+pub fn run<S: Solution>(input: &str) -> Result<Answers> {
+    let (parsed, parse) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let (part1, part1_time) = timed(|| S::part1(&parsed));
+    let (part2, part2_time) = timed(|| S::part2(&parsed));
+    Ok(Answers {
+        part1: part1.into_value().map(|answer| answer.to_string()),
+        part2: part2.into_value().map(|answer| answer.to_string()),
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// Reads the file at `path` and solves part 1 of `S`, printing and returning the answer.
+pub fn solve_part1<S: Solution>(path: impl AsRef<Path>) -> Result<<S::Answer1 as Answer>::Value> {
+    let input = S::parse(&read_input(path)?)?;
+    let result = S::part1(&input).into_value()?;
+    println!("Part 1: {}", result);
+    Ok(result)
+}
+
+/// Reads the file at `path` and solves part 2 of `S`, printing and returning the answer.
+pub fn solve_part2<S: Solution>(path: impl AsRef<Path>) -> Result<<S::Answer2 as Answer>::Value> {
+    let input = S::parse(&read_input(path)?)?;
+    let result = S::part2(&input).into_value()?;
+    println!("Part 2: {}", result);
+    Ok(result)
+}
+
+/// Solves part 1 of `S` on the real input and checks it against the recorded answer.
+///
+/// Without a recorded answer the check is skipped with a note on stderr.
+pub fn verify_part1<S: Solution>() -> Result<()> {
+    let answer = solve_part1::<S>(input_path(S::DAY))?;
+    check_recorded(S::DAY, 1, &answer.to_string())
+}
+
+/// Solves part 2 of `S` on the real input and checks it against the recorded answer.
+///
+/// Without a recorded answer the check is skipped with a note on stderr.
+pub fn verify_part2<S: Solution>() -> Result<()> {
+    let answer = solve_part2::<S>(input_path(S::DAY))?;
+    check_recorded(S::DAY, 2, &answer.to_string())
+}
+
+fn check_recorded(day: u8, part: u8, actual: &str) -> Result<()> {
+    let path = answers_path(day);
+    if !RecordedAnswers::load(&path)?.verify(day, part, actual)? {
+        eprintln!(
+            "skipped: no recorded answer for day {day} part {part} in {}",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+pub fn read_input<P>(filename: P) -> Result<String>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    fs::read_to_string(path).map_err(|source| AocError::MissingInput {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn read_lines<P>(filename: P) -> Result<Lines<BufReader<File>>>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename)?;
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|source| AocError::MissingInput {
+        path: path.to_path_buf(),
+        source,
+    })?;
     Ok(BufReader::new(file).lines())
 }
 
-pub fn input_path(day: u8) -> String {
-    format!("days/day{:02}/input.txt", day)
+/// Writes a file produced by a command, such as an updated README or a rendered frame.
+pub fn write_output<P, C>(path: P, contents: C) -> Result<()>
+where
+    P: AsRef<Path>,
+    C: AsRef<[u8]>,
+{
+    let path = path.as_ref();
+    fs::write(path, contents).map_err(|source| AocError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The directory of `day` within the workspace, e.g. `days/day07`.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root().join(format!("days/day{:02}", day))
+}
+
+pub fn input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+pub fn answers_path(day: u8) -> PathBuf {
+    day_dir(day).join("answers.toml")
 }
 
-pub fn example_path(day: u8) -> String {
-    format!("days/day{:02}/example.txt", day)
+pub fn example_path(day: u8) -> PathBuf {
+    day_dir(day).join("example.txt")
 }
 
-pub fn example_path_n(day: u8, n: u8) -> String {
-    format!("days/day{:02}/example_{}.txt", day, n)
+pub fn example_path_n(day: u8, n: u8) -> PathBuf {
+    day_dir(day).join(format!("example_{}.txt", n))
 }
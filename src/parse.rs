@@ -0,0 +1,238 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{AocError, Result, column_of, parse_token};
+
+/// One line of input together with its 1-based line number, so that errors can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// A parse error at the start of `token`, which must be a subslice of this line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> AocError {
+        AocError::parse(self.number, column_of(self.text, token), message)
+    }
+
+    /// Parses `token`, a subslice of this line, reporting its position on failure.
+    pub fn parse<T>(&self, token: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_token(self.text, self.number, token)
+    }
+
+    /// Splits the whole line at the first `separator`, trimming both halves.
+    pub fn split_pair(&self, separator: &str) -> Result<(&'a str, &'a str)> {
+        self.split_token(self.text, separator)
+    }
+
+    /// Splits `token`, a subslice of this line, at the first `separator`, trimming both halves.
+    pub fn split_token(&self, token: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+        token
+            .split_once(separator)
+            .map(|(left, right)| (left.trim(), right.trim()))
+            .ok_or_else(|| {
+                self.error(
+                    token,
+                    format!("expected {separator:?} in {:?}", token.trim()),
+                )
+            })
+    }
+
+    /// All integers in the line; see [`ints`].
+    pub fn ints<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let signed = "-1".parse::<T>().is_ok();
+        int_tokens(self.text, signed)
+            .map(|token| self.parse(token))
+            .collect()
+    }
+}
+
+/// A run of consecutive non-blank lines, as produced by [`sections`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// The 1-based line number of the section's first line within the whole input.
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + use<'a> {
+        numbered(self.text, self.first_line)
+    }
+
+    /// Parses every line of the section with `record`; see [`records`].
+    pub fn records<T>(&self, record: impl FnMut(Line<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.lines().map(record).collect()
+    }
+
+    /// All integers in the section; see [`ints`].
+    pub fn ints<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut numbers = Vec::new();
+        for line in self.lines() {
+            numbers.extend(line.ints()?);
+        }
+        Ok(numbers)
+    }
+}
+
+/// All lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered(input, 1)
+}
+
+fn numbered(text: &str, first_line: usize) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(move |(index, text)| Line {
+        number: first_line + index,
+        text,
+    })
+}
+
+/// Splits `input` into groups of lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    // The first line number and the byte range of the section being collected.
+    let mut current: Option<(usize, usize, usize)> = None;
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                sections.push(Section {
+                    first_line,
+                    text: &input[start..end],
+                });
+            }
+            continue;
+        }
+        let start = column_of(input, line.text) - 1;
+        let end = start + line.text.len();
+        current = Some(match current {
+            Some((first_line, start, _)) => (first_line, start, end),
+            None => (line.number, start, end),
+        });
+    }
+    if let Some((first_line, start, end)) = current {
+        sections.push(Section {
+            first_line,
+            text: &input[start..end],
+        });
+    }
+    sections
+}
+
+/// Parses every non-blank line of `input` with `record`, collecting the results.
+///
+/// `record` receives each line with its number, so it can report errors through
+/// [`Line::error`] and [`Line::parse`].
+pub fn records<'a, T>(input: &'a str, record: impl FnMut(Line<'a>) -> Result<T>) -> Result<Vec<T>> {
+    lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(record)
+        .collect()
+}
+
+/// Extracts every integer from arbitrary text, such as `x=-3, y=12` or `10-20`.
+///
+/// A `-` directly before a number is taken as its sign when `T` is signed and the `-`
+/// does not follow a letter or digit, so `10-20` yields `10` and `20` either way.
+/// Numbers that do not fit `T` are reported with their position.
+pub fn ints<T>(text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Section {
+        first_line: 1,
+        text,
+    }
+    .ints()
+}
+
+/// Splits `line` at the first `separator`, trimming both halves and reporting a missing separator
+/// at the 1-based `line_number`.
+pub fn split_pair<'a>(
+    line: &'a str,
+    line_number: usize,
+    separator: &str,
+) -> Result<(&'a str, &'a str)> {
+    Line {
+        number: line_number,
+        text: line,
+    }
+    .split_pair(separator)
+}
+
+fn int_tokens(text: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+    std::iter::from_fn(move || {
+        while index < bytes.len() && !bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+        let mut start = index;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if signed
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        Some(&text[start..index])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_respect_signedness_and_separators() {
+        assert_eq!(ints::<i64>("x=-3, y=12 10-20").unwrap(), [-3, 12, 10, 20]);
+        assert_eq!(ints::<u64>("x=-3, y=12").unwrap(), [3, 12]);
+        match ints::<u8>("1\nvalue 300") {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 7)),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sections_keep_their_line_numbers() {
+        let input = "a\nb\n\n\nc\n\nd\n";
+        let sections = sections(input);
+        let starts: Vec<_> = sections.iter().map(|s| (s.first_line, s.text)).collect();
+        assert_eq!(starts, [(1, "a\nb"), (5, "c"), (7, "d")]);
+        assert_eq!(sections[1].lines().next().unwrap().number, 5);
+    }
+
+    #[test]
+    fn records_report_the_failing_line() {
+        let parsed = records("1-2\n\n3-4\n", |line| {
+            let (start, end) = line.split_pair("-")?;
+            Ok((line.parse::<u8>(start)?, line.parse::<u8>(end)?))
+        });
+        assert_eq!(parsed.unwrap(), [(1, 2), (3, 4)]);
+
+        match records("1-2\n3\n", |line| line.split_pair("-").map(|_| ())) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{AocError, Result};
+
+/// Marks the generated part of the README; everything between them is replaced.
+pub const PROGRESS_START: &str = "<!-- progress:start -->";
+pub const PROGRESS_END: &str = "<!-- progress:end -->";
+
+/// How far along one part of a day is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The answer is recorded in `answers.toml` and, where the input is present, still reproduced.
+    Solved,
+    /// The part has a real implementation, but no accepted answer yet.
+    InProgress,
+    /// The part only returns a placeholder.
+    Stub,
+}
+
+impl PartStatus {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "⭐",
+            PartStatus::InProgress => "🎅",
+            PartStatus::Stub => "💤",
+        }
+    }
+}
+
+/// Marks the body of a part that has not been started; the `new_day` templates put it in both
+/// parts, and it is removed when the part gets a real implementation.
+pub const STUB_MARKER: &str = "// STUB";
+
+/// Which of `part1` and `part2` in a day's source are stubs: a body containing
+/// [`STUB_MARKER`], or that is only `todo!()` or `unimplemented!()`.
+pub fn stub_parts(source: &str) -> [bool; 2] {
+    ["part1", "part2"].map(|name| {
+        function_body(source, name).is_some_and(|body| {
+            let code: String = body
+                .lines()
+                .map(|line| line.split("//").next().unwrap_or_default())
+                .collect::<String>()
+                .split_whitespace()
+                .collect();
+            body.contains(STUB_MARKER) || matches!(code.as_str(), "todo!()" | "unimplemented!()")
+        })
+    })
+}
+
+/// The text between the braces of `fn name(...)`, assuming balanced braces in its body.
+fn function_body<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source.find(&format!("fn {name}("))?;
+    let open = start + source[start..].find('{')?;
+    let mut depth = 0;
+    for (offset, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[open + 1..open + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Renders the progress table for `days`, given in day order.
+pub fn progress_table(days: &[(u8, [PartStatus; 2])]) -> String {
+    let mut table = String::from("| Day | Stars |\n| --- | ----- |\n");
+    for (day, parts) in days {
+        let stars: String = parts.iter().map(|part| part.symbol()).collect();
+        table.push_str(&format!("| {day:02}  | {stars}  |\n"));
+    }
+    table
+}
+
+/// Replaces everything between [`PROGRESS_START`] and [`PROGRESS_END`] in `readme` with `table`.
+pub fn replace_progress(readme: &str, table: &str) -> Result<String> {
+    let missing = || {
+        AocError::shape(format!(
+            "README has no {PROGRESS_START} ... {PROGRESS_END} section"
+        ))
+    };
+    let start = readme.find(PROGRESS_START).ok_or_else(missing)? + PROGRESS_START.len();
+    let end = start + readme[start..].find(PROGRESS_END).ok_or_else(missing)?;
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_marked_stubs() {
+        let source = "fn part1(input: &Input) -> u64 {\n    let result = 0;\n    result\n}\n\
+                      fn part2(_input: &Input) -> u64 {\n    0 // STUB\n}\n";
+        assert_eq!(stub_parts(source), [false, true]);
+        assert_eq!(stub_parts("fn part1() { todo!() }"), [true, false]);
+        // An answer of 0 is not a stub on its own.
+        assert_eq!(
+            stub_parts("fn part1() { 0 }\nfn part2() { 0 }"),
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn replaces_only_the_marked_section() {
+        let readme = format!("# AoC\n{PROGRESS_START}\nold\n{PROGRESS_END}\nrest\n");
+        let table = progress_table(&[(1, [PartStatus::Solved, PartStatus::Stub])]);
+        let updated = replace_progress(&readme, &table).unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# AoC\n{PROGRESS_START}\n| Day | Stars |\n| --- | ----- |\n| 01  | ⭐💤  |\n{PROGRESS_END}\nrest\n"
+            )
+        );
+        assert_eq!(replace_progress(&updated, &table).unwrap(), updated);
+        assert!(replace_progress("# AoC\n", &table).is_err());
+    }
+}
@@ -0,0 +1,240 @@
+/// What can be learned offline from a saved puzzle page: its title, the example blocks and
+/// the highlighted example answer of each part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PuzzlePage {
+    pub title: Option<String>,
+    /// Distinct `<pre><code>` blocks in page order. The first is written to `example.txt`,
+    /// the n-th to `example_n.txt`.
+    pub examples: Vec<String>,
+    pub parts: Vec<PartExample>,
+}
+
+/// The example a part's description works through and the answer it arrives at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartExample {
+    /// Index into [`PuzzlePage::examples`]: the part's first block, or the first block of the
+    /// page when the part reuses the earlier example.
+    pub example: usize,
+    /// The last highlighted `<code><em>` value of the part, by convention the example answer.
+    pub answer: Option<String>,
+}
+
+impl PuzzlePage {
+    /// Extracts the examples and answers from the HTML of a puzzle page as saved by a browser.
+    pub fn parse(html: &str) -> Self {
+        let mut page = PuzzlePage {
+            title: between(html, "<h2>", "</h2>").map(|title| {
+                let title = decode(title);
+                title.trim().trim_matches('-').trim().to_string()
+            }),
+            ..Self::default()
+        };
+
+        for article in elements(html, "<article", "</article>") {
+            let mut first_block = None;
+            for block in elements(article, "<pre><code>", "</code></pre>") {
+                let mut example = decode(block);
+                if !example.ends_with('\n') {
+                    example.push('\n');
+                }
+                let index = match page.examples.iter().position(|e| *e == example) {
+                    Some(index) => index,
+                    None => {
+                        page.examples.push(example);
+                        page.examples.len() - 1
+                    }
+                };
+                first_block.get_or_insert(index);
+            }
+            let answer = elements(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| decode(answer).trim().to_string());
+            page.parts.push(PartExample {
+                example: first_block.unwrap_or(0),
+                answer,
+            });
+        }
+        page
+    }
+}
+
+/// The contents of every `start ... end` element in `html`, without the delimiters.
+///
+/// `start` may be an unterminated tag such as `<article`, in which case its attributes are skipped.
+fn elements<'a>(html: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(open) = rest.find(start) {
+        let after = &rest[open + start.len()..];
+        let content = if start.ends_with('>') {
+            after
+        } else {
+            match after.find('>') {
+                Some(close) => &after[close + 1..],
+                None => break,
+            }
+        };
+        let Some(close) = content.find(end) else {
+            break;
+        };
+        found.push(&content[..close]);
+        rest = &content[close + end.len()..];
+    }
+    found
+}
+
+fn between<'a>(html: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    elements(html, start, end).into_iter().next()
+}
+
+/// Strips tags such as `<em>` and decodes the entities that appear in puzzle text.
+fn decode(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Fills in the `partN_example` assertion of a day's `mod.rs` generated by `new_day`.
+///
+/// Only an assertion still marked `// TODO: fill in expected value` is replaced, so answers
+/// entered by hand are kept; `None` is returned when there is none. `example` selects
+/// `example_path_n(DAY, n)` instead of `example_path(DAY)`, and the test imports are updated to match.
+pub fn fill_example_assertion(
+    source: &str,
+    part: u8,
+    example: Option<u8>,
+    answer: &str,
+) -> Option<String> {
+    const TODO: &str = "// TODO: fill in expected value";
+    let call = format!("solve_part{part}::<");
+
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+    let line = lines
+        .iter_mut()
+        .find(|line| line.contains(&call) && line.contains(TODO))?;
+    let start = line.find(&call)?;
+    let type_end = start + line[start..].find(">(")? + 2;
+    let path = match example {
+        Some(n) => format!("example_path_n(DAY, {n})"),
+        None => "example_path(DAY)".to_string(),
+    };
+    *line = format!("{}{path})?, {answer});", &line[..type_end]);
+
+    let mut filled = lines.join("\n");
+    if source.ends_with('\n') {
+        filled.push('\n');
+    }
+    Some(fix_example_imports(&filled))
+}
+
+/// Rewrites the `use adventofcode25::{...}` list that imports the example paths so that it
+/// names exactly the ones `source` calls. The list may span several lines.
+fn fix_example_imports(source: &str) -> String {
+    const IMPORT: &str = "use adventofcode25::{";
+    let mut offset = 0;
+    while let Some(found) = source[offset..].find(IMPORT) {
+        let start = offset + found;
+        let names_start = start + IMPORT.len();
+        let Some(names_len) = source[names_start..].find('}') else {
+            break;
+        };
+        let names = &source[names_start..names_start + names_len];
+        offset = names_start + names_len;
+        if !names.contains("example_path") {
+            continue;
+        }
+
+        let mut names: Vec<&str> = names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty() && !name.starts_with("example_path"))
+            .collect();
+        for name in ["example_path", "example_path_n"] {
+            if source.contains(&format!("{name}(")) {
+                names.push(name);
+            }
+        }
+        names.sort_unstable();
+        return format!(
+            "{}{IMPORT}{}{}",
+            &source[..start],
+            names.join(", "),
+            &source[offset..]
+        );
+    }
+    source.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 7: Laboratories ---</h2><p>For example:</p>
+<pre><code>.S.
+.^.
+</code></pre>
+<p>A total of <code><em>1</em></code> split, after <code><em>2</em></code> steps... <code><em>21</em></code> times.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now with:</p>
+<pre><code>a &lt;-&gt; <em>b</em>
+</code></pre><p>Gives <code><em>40</em></code>.</p></article>
+</main>"#;
+
+    #[test]
+    fn extracts_examples_and_answers_per_part() {
+        let page = PuzzlePage::parse(PAGE);
+        assert_eq!(page.title.as_deref(), Some("Day 7: Laboratories"));
+        assert_eq!(page.examples, [".S.\n.^.\n", "a <-> b\n"]);
+        assert_eq!(
+            page.parts,
+            [
+                PartExample {
+                    example: 0,
+                    answer: Some("21".to_string())
+                },
+                PartExample {
+                    example: 1,
+                    answer: Some("40".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_only_todo_assertions_and_fixes_imports() {
+        let source = "mod tests {\n    use adventofcode25::{example_path, solve_part1, solve_part2};\n\n        \
+                      assert_eq!(solve_part1::<Day07>(example_path(DAY))?, 0); // TODO: fill in expected value\n        \
+                      assert_eq!(solve_part2::<Day07>(example_path(DAY))?, 0); // TODO: fill in expected value\n}\n";
+        let filled = fill_example_assertion(source, 2, Some(2), "40").unwrap();
+        assert!(
+            filled.contains("assert_eq!(solve_part2::<Day07>(example_path_n(DAY, 2))?, 40);\n")
+        );
+        assert!(filled.contains(
+            "use adventofcode25::{example_path, example_path_n, solve_part1, solve_part2};"
+        ));
+
+        let filled = fill_example_assertion(&filled, 1, None, "21").unwrap();
+        assert!(filled.contains("assert_eq!(solve_part1::<Day07>(example_path(DAY))?, 21);\n"));
+        assert_eq!(fill_example_assertion(&filled, 1, None, "5"), None);
+
+        let multiline =
+            "use adventofcode25::{\n    example_path, solve_part1,\n};\nexample_path_n(DAY, 2)";
+        assert!(
+            fix_example_imports(multiline)
+                .starts_with("use adventofcode25::{example_path_n, solve_part1};")
+        );
+    }
+}
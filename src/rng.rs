@@ -0,0 +1,22 @@
+//! A small deterministic random number generator for randomized tests.
+//!
+//! Shared by the library's tests and, through `#[path]`, by the days' tests in the `aoc` binary.
+
+/// An xorshift sequence from a fixed seed, so failures are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Starts the sequence at `seed`, which must not be zero.
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "an xorshift seed must not be zero");
+        Rng(seed)
+    }
+
+    /// The next value, below `bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
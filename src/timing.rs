@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+
+/// How long each phase of a single run took.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Runs `f`, returning its result together with the elapsed wall-clock time.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Summary statistics over repeated measurements of the same phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Returns `None` for an empty sample.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_over_odd_and_even_samples() {
+        let ms = Duration::from_millis;
+        let odd = Stats::from_samples(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((odd.min, odd.median, odd.mean), (ms(1), ms(3), ms(3)));
+
+        let even = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!((even.min, even.median, even.mean), (ms(1), ms(3), ms(4)));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{AocError, InputSource, Result};
+
+/// An extra command a day offers besides solving, such as a trace or a visualisation.
+///
+/// Run with `aoc tool <day> <name> [args...]`; the runner strips the input options
+/// (`--example`, `--input`, `-`) and passes the chosen source along.
+#[derive(Clone, Copy)]
+pub struct Tool {
+    pub name: &'static str,
+    /// The arguments the tool takes and what it prints, shown in the tool list.
+    pub usage: &'static str,
+    pub run: fn(&InputSource, &[String]) -> Result<()>,
+}
+
+/// The arguments passed to a [`Tool`], split into `--flag` switches, `--name value`
+/// options and positional arguments.
+#[derive(Debug, Default)]
+pub struct ToolArgs {
+    flags: Vec<String>,
+    options: Vec<(String, String)>,
+    positional: Vec<String>,
+}
+
+impl ToolArgs {
+    /// Accepts only the given `flags` and `options`; anything else starting with `--` is an error.
+    pub fn parse(args: &[String], flags: &[&str], options: &[&str]) -> Result<Self> {
+        let mut parsed = ToolArgs::default();
+        let mut rest = args.iter();
+        while let Some(arg) = rest.next() {
+            if flags.contains(&arg.as_str()) {
+                parsed.flags.push(arg.clone());
+            } else if options.contains(&arg.as_str()) {
+                let value = rest
+                    .next()
+                    .ok_or_else(|| AocError::InvalidArgument(format!("{arg} needs a value")))?;
+                parsed.options.push((arg.clone(), value.clone()));
+            } else if arg.starts_with("--") {
+                let known: Vec<&str> = flags.iter().chain(options).copied().collect();
+                return Err(AocError::InvalidArgument(format!(
+                    "unknown option {arg}, expected one of: {}",
+                    known.join(", ")
+                )));
+            } else {
+                parsed.positional.push(arg.clone());
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// The last value given for option `name`, parsed.
+    pub fn value<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.values(name).pop().transpose()
+    }
+
+    /// Every value given for option `name`, parsed, for options that may repeat.
+    pub fn values<T>(&self, name: &str) -> Vec<Result<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.options
+            .iter()
+            .filter(|(option, _)| option == name)
+            .map(|(option, value)| {
+                value
+                    .parse()
+                    .map_err(|e| AocError::InvalidArgument(format!("{option} {value}: {e}")))
+            })
+            .collect()
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_flags_options_and_positional() {
+        let parsed = ToolArgs::parse(
+            &args(&["--csv", "--size", "10", "out.txt", "--size", "12"]),
+            &["--csv"],
+            &["--size"],
+        )
+        .unwrap();
+        assert!(parsed.flag("--csv"));
+        assert_eq!(parsed.value::<u64>("--size").unwrap(), Some(12));
+        assert_eq!(parsed.value::<u64>("--start").unwrap(), None);
+        assert_eq!(parsed.positional(), ["out.txt"]);
+
+        assert!(ToolArgs::parse(&args(&["--size"]), &[], &["--size"]).is_err());
+        assert!(ToolArgs::parse(&args(&["--nope"]), &["--csv"], &[]).is_err());
+        let bad = ToolArgs::parse(&args(&["--size", "x"]), &[], &["--size"]).unwrap();
+        assert!(matches!(
+            bad.value::<u64>("--size"),
+            Err(AocError::InvalidArgument(_))
+        ));
+    }
+}
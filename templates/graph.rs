@@ -0,0 +1,66 @@
+//! {{title}}
+
+use std::collections::HashMap;
+
+use adventofcode25::parse::records;
+use adventofcode25::{Result, Solution};
+
+const DAY: u8 = {{day}};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = DAY;
+
+    type Input = HashMap<String, Vec<String>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let edges = records(input, |line| {
+            let (node, neighbors) = line.split_pair(":")?;
+            let neighbors = neighbors.split_whitespace().map(str::to_string).collect();
+            Ok((node.to_string(), neighbors))
+        })?;
+        Ok(edges.into_iter().collect())
+    }
+
+    fn part1(graph: &Self::Input) -> u64 {
+        for (node, neighbors) in graph {
+            println!("{node} -> {neighbors:?}");
+        }
+        0 // STUB
+    }
+
+    fn part2(_graph: &Self::Input) -> u64 {
+        0 // STUB
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day{{day_padded}}>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day{{day_padded}}>()
+    }
+}
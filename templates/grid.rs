@@ -0,0 +1,56 @@
+//! {{title}}
+
+use adventofcode25::{Grid, Result, Solution};
+
+const DAY: u8 = {{day}};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = DAY;
+
+    type Input = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_str(input, Some)
+    }
+
+    fn part1(grid: &Self::Input) -> u64 {
+        print!("{grid}");
+        0 // STUB
+    }
+
+    fn part2(_grid: &Self::Input) -> u64 {
+        0 // STUB
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day{{day_padded}}>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day{{day_padded}}>()
+    }
+}
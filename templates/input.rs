@@ -0,0 +1,73 @@
+//! {{title}}
+
+use adventofcode25::parse::sections;
+use adventofcode25::{AocError, Result, Solution};
+
+const DAY: u8 = {{day}};
+
+pub struct Day{{day_padded}};
+
+pub struct Input {
+    lines: Vec<String>,
+}
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = DAY;
+
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Input> {
+        let lines = match sections(input)[..] {
+            [] => Vec::new(),
+            [section] => section.records(|line| Ok(line.text.to_string()))?,
+            [_, extra, ..] => {
+                return Err(AocError::shape(format!(
+                    "expected one section, found another at line {}",
+                    extra.first_line
+                )));
+            }
+        };
+        Ok(Input { lines })
+    }
+
+    fn part1(input: &Input) -> u64 {
+        for line in &input.lines {
+            println!("{line}");
+        }
+        0 // STUB
+    }
+
+    fn part2(_input: &Input) -> u64 {
+        0 // STUB
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day{{day_padded}}>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day{{day_padded}}>()
+    }
+}
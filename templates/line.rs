@@ -0,0 +1,59 @@
+//! {{title}}
+
+use adventofcode25::parse::records;
+use adventofcode25::{Result, Solution};
+
+const DAY: u8 = {{day}};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = DAY;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        records(input, |line| line.ints())
+    }
+
+    fn part1(records: &Self::Input) -> u64 {
+        for record in records {
+            println!("{record:?}");
+        }
+        0 // STUB
+    }
+
+    fn part2(_records: &Self::Input) -> u64 {
+        0 // STUB
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode25::{example_path, solve_part1, solve_part2, verify_part1, verify_part2};
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(solve_part1::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part1_real() -> Result<()> {
+        verify_part1::<Day{{day_padded}}>()
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(solve_part2::<Day{{day_padded}}>(example_path(DAY))?, 0); // TODO: fill in expected value
+        Ok(())
+    }
+
+    #[test]
+    fn part2_real() -> Result<()> {
+        verify_part2::<Day{{day_padded}}>()
+    }
+}